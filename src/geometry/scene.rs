@@ -0,0 +1,319 @@
+use std::{
+    cell::{Cell, RefCell},
+    iter,
+};
+
+use super::{shape::Shape, transform::Similarity};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId(usize);
+
+#[derive(Debug, Clone)]
+struct Node {
+    local: Similarity,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    shapes: Vec<Shape>,
+    // Parent world transform composed with `local`, valid unless `dirty`. A dirty node only
+    // ever has dirty descendants.
+    world: RefCell<Similarity>,
+    dirty: Cell<bool>,
+}
+
+// Tree of nodes, each placed relative to its parent. World transforms are worked out on
+// demand and kept until something above them moves.
+#[derive(Debug, Clone, Default)]
+pub struct Scene {
+    // Removed nodes leave a hole so the ids of the others stay valid
+    nodes: Vec<Option<Node>>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, parent: Option<NodeId>, local: Similarity) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        if let Some(parent) = parent {
+            self.node_mut(parent).children.push(id);
+        }
+        self.nodes.push(Some(Node {
+            local,
+            parent,
+            children: Vec::new(),
+            shapes: Vec::new(),
+            world: RefCell::new(Similarity::IDENT),
+            dirty: Cell::new(true),
+        }));
+        id
+    }
+
+    // Removes the node together with everything attached below it
+    pub fn remove(&mut self, id: NodeId) {
+        if let Some(parent) = self.node(id).parent {
+            self.node_mut(parent).children.retain(|&child| child != id);
+        }
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.nodes[id.0].take().expect("Unknown scene node");
+            stack.extend(node.children);
+        }
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.nodes.get(id.0).is_some_and(Option::is_some)
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.node(id).children
+    }
+
+    // Moves the node under a new parent, keeping its local transform
+    pub fn set_parent(&mut self, id: NodeId, parent: Option<NodeId>) {
+        let mut ancestor = parent;
+        while let Some(node) = ancestor {
+            assert_ne!(node, id, "A scene node cannot be attached below itself");
+            ancestor = self.node(node).parent;
+        }
+
+        if let Some(old) = self.node(id).parent {
+            self.node_mut(old).children.retain(|&child| child != id);
+        }
+        if let Some(parent) = parent {
+            self.node_mut(parent).children.push(id);
+        }
+        self.node_mut(id).parent = parent;
+        self.invalidate(id);
+    }
+
+    pub fn local(&self, id: NodeId) -> &Similarity {
+        &self.node(id).local
+    }
+
+    pub fn set_local(&mut self, id: NodeId, local: Similarity) {
+        self.node_mut(id).local = local;
+        self.invalidate(id);
+    }
+
+    pub fn world(&self, id: NodeId) -> Similarity {
+        let node = self.node(id);
+        if node.dirty.get() {
+            let world = match node.parent {
+                Some(parent) => self.world(parent).compose(&node.local),
+                None => node.local.clone(),
+            };
+            *node.world.borrow_mut() = world;
+            node.dirty.set(false);
+        }
+        node.world.borrow().clone()
+    }
+
+    pub fn shapes(&self, id: NodeId) -> &[Shape] {
+        &self.node(id).shapes
+    }
+
+    // Shapes are given in the frame of their node
+    pub fn add_shape(&mut self, id: NodeId, shape: Shape) {
+        self.node_mut(id).shapes.push(shape);
+    }
+
+    pub fn world_shapes(&self, id: NodeId) -> impl Iterator<Item = Shape> + '_ {
+        let world = self.world(id);
+        self.shapes(id)
+            .iter()
+            .map(move |shape| shape.clone() * &world)
+    }
+
+    // Every node that is still in the scene, parents before their children
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> + '_ {
+        let mut stack: Vec<NodeId> = self
+            .ids()
+            .filter(|&id| self.node(id).parent.is_none())
+            .collect();
+        stack.reverse();
+        iter::from_fn(move || {
+            let id = stack.pop()?;
+            stack.extend(self.node(id).children.iter().rev());
+            Some(id)
+        })
+    }
+
+    fn ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len())
+            .map(NodeId)
+            .filter(|&id| self.contains(id))
+    }
+
+    fn invalidate(&self, id: NodeId) {
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            let node = self.node(id);
+            // Everything below a dirty node is dirty already
+            if !node.dirty.replace(true) {
+                stack.extend(&node.children);
+            }
+        }
+    }
+
+    fn node(&self, id: NodeId) -> &Node {
+        self.nodes[id.0].as_ref().expect("Unknown scene node")
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> &mut Node {
+        self.nodes[id.0].as_mut().expect("Unknown scene node")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{
+            scalar::consts::FRAC_PI_2,
+            shape::{Point, Rounded},
+            vector,
+        },
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    // A car with a wheel, and a driver's hand holding a cup
+    fn scene() -> (Scene, [NodeId; 4]) {
+        let mut scene = Scene::new();
+        let car = scene.add(
+            None,
+            Similarity::from_parts(2.0, FRAC_PI_2, vector!(10.0, 0.0)),
+        );
+        let wheel = scene.add(
+            Some(car),
+            Similarity::from_parts(1.0, 0.0, vector!(1.5, -0.5)),
+        );
+        let hand = scene.add(
+            Some(car),
+            Similarity::from_parts(0.5, 0.3, vector!(0.0, 1.0)),
+        );
+        let cup = scene.add(
+            Some(hand),
+            Similarity::from_parts(1.0, -0.3, vector!(0.2, 0.0)),
+        );
+        scene.add_shape(
+            wheel,
+            Shape::from(Rounded::new(Shape::from(Point::ORIGIN), 0.5)),
+        );
+        (scene, [car, wheel, hand, cup])
+    }
+
+    #[test]
+    fn world() {
+        let (scene, [car, wheel, hand, cup]) = scene();
+        let v = vector!(0.3, -0.7);
+
+        assert_approx_eq!(scene.world(car), scene.local(car).clone());
+        assert_approx_eq!(
+            &scene.world(cup) * v,
+            scene.local(car) * (scene.local(hand) * (scene.local(cup) * v))
+        );
+        assert_approx_eq!(
+            scene.world(wheel),
+            Similarity::from_parts(2.0, FRAC_PI_2, vector!(11.0, 3.0))
+        );
+    }
+
+    #[test]
+    fn moving_a_parent_moves_its_children() {
+        let (mut scene, [car, wheel, hand, cup]) = scene();
+        let before = scene.world(cup);
+        scene.world(wheel);
+
+        let moved = Similarity::from_parts(1.0, 0.0, vector!(-4.0, 2.0));
+        scene.set_local(car, moved.clone());
+        assert_approx_eq!(scene.world(car), moved);
+        assert_approx_eq!(
+            scene.world(wheel),
+            Similarity::from_parts(1.0, 0.0, vector!(-2.5, 1.5))
+        );
+        assert_ne!(scene.world(cup), before);
+
+        // Only the hand turns, so the wheel keeps its place
+        scene.set_local(hand, Similarity::IDENT);
+        assert_approx_eq!(
+            scene.world(wheel),
+            Similarity::from_parts(1.0, 0.0, vector!(-2.5, 1.5))
+        );
+        assert_approx_eq!(scene.world(cup), moved.compose(scene.local(cup)));
+    }
+
+    #[test]
+    fn set_parent() {
+        let (mut scene, [car, wheel, hand, cup]) = scene();
+        scene.world(cup);
+
+        // The cup is put down on the wheel
+        scene.set_parent(cup, Some(wheel));
+        assert_eq!(scene.parent(cup), Some(wheel));
+        assert_eq!(scene.children(hand), &[]);
+        assert_eq!(scene.children(wheel), &[cup]);
+        assert_approx_eq!(
+            scene.world(cup),
+            scene.world(wheel).compose(scene.local(cup))
+        );
+
+        scene.set_parent(cup, None);
+        assert_approx_eq!(scene.world(cup), scene.local(cup).clone());
+        assert_eq!(
+            scene.nodes().collect::<Vec<_>>(),
+            vec![car, wheel, hand, cup]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_parent_cycle() {
+        let (mut scene, [car, _, _, cup]) = scene();
+        scene.set_parent(car, Some(cup));
+    }
+
+    #[test]
+    fn remove() {
+        let (mut scene, [car, wheel, hand, cup]) = scene();
+        scene.remove(hand);
+
+        assert!(!scene.contains(hand));
+        assert!(!scene.contains(cup));
+        assert_eq!(scene.children(car), &[wheel]);
+        assert_eq!(scene.nodes().collect::<Vec<_>>(), vec![car, wheel]);
+    }
+
+    #[test]
+    fn world_shapes() {
+        let (mut scene, [car, wheel, ..]) = scene();
+        let shapes: Vec<Shape> = scene.world_shapes(wheel).collect();
+        assert_eq!(shapes.len(), 1);
+        assert_approx_eq!(shapes[0].distance_to_point(Point::new(11.0, 3.0)), -1.0);
+
+        // Mirroring the car keeps the wheel round
+        scene.set_local(car, Similarity::from_reflection(0.0));
+        let shapes: Vec<Shape> = scene.world_shapes(wheel).collect();
+        assert_approx_eq!(shapes[0].distance_to_point(Point::new(1.5, 0.5)), -0.5);
+    }
+
+    #[bench]
+    fn world_bench(b: &mut Bencher) {
+        let mut scene = Scene::new();
+        let root = scene.add(None, Similarity::IDENT);
+        let step = Similarity::from_parts(1.0, 0.01, vector!(1.0, 0.0));
+        let leaf = (0..100).fold(root, |parent, _| scene.add(Some(parent), step.clone()));
+
+        b.iter(|| {
+            scene.set_local(root, Similarity::IDENT);
+            black_box(scene.world(leaf))
+        });
+    }
+}
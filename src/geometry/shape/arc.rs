@@ -0,0 +1,348 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        scalar::consts::{FRAC_PI_2, PI, TAU},
+        transform::Similarity,
+        vector, Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Chain, Point, Rect, SignedDistance};
+
+// Counterclockwise when `end_angle` is greater than `start_angle`, clockwise otherwise
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arc {
+    pub center: Point,
+    pub radius: Scalar,
+    pub start_angle: Scalar,
+    pub end_angle: Scalar,
+}
+
+impl Arc {
+    pub const fn new(
+        center: Point,
+        radius: Scalar,
+        start_angle: Scalar,
+        end_angle: Scalar,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        }
+    }
+
+    // The bulge is the tangent of a quarter of the swept angle, positive for counterclockwise
+    // arcs, as used by DXF polylines. A zero bulge is a straight line and gives no arc.
+    pub fn from_bulge(start: Point, end: Point, bulge: Scalar) -> Option<Self> {
+        let chord = end - start;
+        if bulge == 0.0 || chord.norm_sq() == 0.0 {
+            return None;
+        }
+
+        let center = start + chord / 2.0 + chord.perp() * ((1.0 - bulge * bulge) / (4.0 * bulge));
+        let radius = chord.norm() * (1.0 + bulge * bulge) / (4.0 * bulge.abs());
+        let start_angle = angle_of(start - center);
+        Some(Self::new(
+            center,
+            radius,
+            start_angle,
+            start_angle + 4.0 * bulge.atan(),
+        ))
+    }
+
+    pub fn sweep(&self) -> Scalar {
+        self.end_angle - self.start_angle
+    }
+
+    pub fn bulge(&self) -> Scalar {
+        (self.sweep() / 4.0).tan()
+    }
+
+    pub fn start_point(&self) -> Point {
+        self.point_at_angle(self.start_angle)
+    }
+
+    pub fn end_point(&self) -> Point {
+        self.point_at_angle(self.end_angle)
+    }
+
+    pub fn point_at(&self, t: Scalar) -> Point {
+        self.point_at_angle(self.start_angle + t * self.sweep())
+    }
+
+    pub fn length(&self) -> Scalar {
+        self.radius * self.sweep().abs()
+    }
+
+    pub fn contains_angle(&self, angle: Scalar) -> bool {
+        let sweep = self.sweep();
+        if sweep.abs() >= TAU {
+            return true;
+        }
+
+        if sweep >= 0.0 {
+            (angle - self.start_angle).rem_euclid(TAU) <= sweep
+        } else {
+            (self.start_angle - angle).rem_euclid(TAU) <= -sweep
+        }
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        let offset = point - self.center;
+        if offset.norm_sq() == 0.0 {
+            return self.start_point();
+        }
+
+        let angle = angle_of(offset);
+        if self.contains_angle(angle) {
+            return self.point_at_angle(angle);
+        }
+
+        let start = self.start_point();
+        let end = self.end_point();
+        if point.distance(start) <= point.distance(end) {
+            start
+        } else {
+            end
+        }
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        point.distance(self.closest_point(point))
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        let extremes = (0..4)
+            .map(|i| i as Scalar * FRAC_PI_2)
+            .filter(|&angle| self.contains_angle(angle))
+            .map(|angle| self.point_at_angle(angle));
+        Rect::from_points(
+            [self.start_point(), self.end_point()]
+                .into_iter()
+                .chain(extremes),
+        )
+        .unwrap()
+    }
+
+    // Chain whose segments stay within `tolerance` of the arc
+    pub fn flatten(&self, tolerance: Scalar) -> Chain {
+        assert!(tolerance > 0.0, "Flattening tolerance must be positive");
+
+        let max_step = if tolerance < self.radius {
+            2.0 * (1.0 - tolerance / self.radius).acos()
+        } else {
+            PI
+        };
+        let parts = (self.sweep().abs() / max_step).ceil().max(1.0) as usize;
+        (0..=parts)
+            .map(|i| self.point_at(i as Scalar / parts as Scalar))
+            .collect()
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        let rotation = t.rotation();
+        self.center *= t;
+        self.radius *= t.scale();
+        // A mirror keeps the endpoints but reverses the direction of travel
+        if t.reflects() {
+            self.start_angle = rotation - self.start_angle;
+            self.end_angle = rotation - self.end_angle;
+        } else {
+            self.start_angle += rotation;
+            self.end_angle += rotation;
+        }
+    }
+
+    fn point_at_angle(&self, angle: Scalar) -> Point {
+        let (sin, cos) = angle.sin_cos();
+        self.center + vector!(cos, sin) * self.radius
+    }
+}
+
+fn angle_of(vector: Vector) -> Scalar {
+    vector.y.atan2(vector.x)
+}
+
+impl SignedDistance for Arc {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for Arc {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && self.radius.approx_eq(&other.radius, epsilon)
+            && self.start_angle.approx_eq(&other.start_angle, epsilon)
+            && self.end_angle.approx_eq(&other.end_angle, epsilon)
+    }
+}
+
+impl Mul<Arc> for &Similarity {
+    type Output = Arc;
+
+    fn mul(self, mut rhs: Arc) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Arc {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::transform::{ScaledRotation, Translation},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn quarter() -> Arc {
+        Arc::new(Point::new(1.0, 1.0), 2.0, 0.0, FRAC_PI_2)
+    }
+
+    #[test]
+    fn end_points() {
+        let arc = quarter();
+
+        assert_approx_eq!(arc.start_point(), Point::new(3.0, 1.0));
+        assert_approx_eq!(arc.end_point(), Point::new(1.0, 3.0));
+        assert_approx_eq!(
+            arc.point_at(0.5),
+            Point::new(1.0 + Scalar::sqrt(2.0), 1.0 + Scalar::sqrt(2.0))
+        );
+        assert_approx_eq!(arc.length(), PI);
+    }
+
+    #[test]
+    fn from_bulge() {
+        let semicircle = Arc::from_bulge(Point::new(0.0, 0.0), Point::new(2.0, 0.0), 1.0).unwrap();
+        assert_approx_eq!(semicircle, Arc::new(Point::new(1.0, 0.0), 1.0, PI, TAU));
+
+        let clockwise = Arc::from_bulge(Point::new(0.0, 0.0), Point::new(2.0, 2.0), -0.3).unwrap();
+        assert_approx_eq!(
+            clockwise.start_point(),
+            Point::new(0.0, 0.0),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            clockwise.end_point(),
+            Point::new(2.0, 2.0),
+            4.0 * Scalar::EPSILON
+        );
+        assert!(clockwise.sweep() < 0.0);
+        assert_approx_eq!(clockwise.bulge(), -0.3);
+
+        assert_eq!(
+            Arc::from_bulge(Point::new(0.0, 0.0), Point::new(2.0, 0.0), 0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn contains_angle() {
+        let arc = Arc::new(Point::ORIGIN, 1.0, 3.0, 4.0);
+        assert!(arc.contains_angle(3.5));
+        assert!(arc.contains_angle(3.5 - TAU));
+        assert!(!arc.contains_angle(0.0));
+
+        let clockwise = Arc::new(Point::ORIGIN, 1.0, 0.5, -0.5);
+        assert!(clockwise.contains_angle(0.0));
+        assert!(!clockwise.contains_angle(PI));
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let arc = quarter();
+
+        assert_approx_eq!(arc.distance_to_point(Point::new(4.0, 5.0)), 3.0);
+        assert_approx_eq!(
+            arc.distance_to_point(Point::new(1.5, 1.5)),
+            2.0 - Scalar::sqrt(0.5),
+            4.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(arc.distance_to_point(Point::new(3.0, -1.0)), 2.0);
+        assert_approx_eq!(arc.distance_to_point(Point::new(1.0, 1.0)), 2.0);
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            quarter().bounding_box(),
+            Rect::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0))
+        );
+
+        let arc = Arc::new(Point::ORIGIN, 1.0, FRAC_PI_2 / 2.0, PI);
+        assert_approx_eq!(
+            arc.bounding_box(),
+            Rect::new(Point::new(-1.0, 0.0), Point::new(Scalar::sqrt(0.5), 1.0))
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        let arc = Arc::new(Point::new(1.0, 1.0), 2.0, 0.3, 5.0);
+        let tolerance = 0.01;
+        let chain = arc.flatten(tolerance);
+
+        assert_approx_eq!(chain.vertices[0], arc.start_point());
+        assert_approx_eq!(chain.vertices[chain.num_vertices() - 1], arc.end_point());
+        for segment in chain.segments() {
+            let midpoint = segment.point_at(0.5);
+            assert!(arc.distance_to_point(midpoint) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut arc = quarter();
+        arc *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(2.0, -1.0));
+
+        assert_approx_eq!(
+            arc,
+            Arc::new(Point::new(0.0, 1.0), 4.0, FRAC_PI_2, PI),
+            8.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn mirror() {
+        let arc = quarter();
+        let t = Similarity::new_reflected(
+            ScaledRotation::from_parts(2.0, FRAC_PI_2),
+            Translation::new(2.0, -1.0),
+        );
+        let mirrored = &t * arc.clone();
+
+        assert!(mirrored.sweep() < 0.0);
+        assert_approx_eq!(mirrored.length(), 2.0 * arc.length());
+        for s in [0.0, 0.3, 1.0] {
+            assert_approx_eq!(
+                mirrored.point_at(s),
+                &t * arc.point_at(s),
+                4.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[bench]
+    fn distance_to_point_bench(b: &mut Bencher) {
+        let arc = quarter();
+        let point = Point::new(4.0, 5.0);
+
+        b.iter(|| black_box(arc.distance_to_point(point)));
+    }
+}
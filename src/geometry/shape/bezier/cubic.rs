@@ -0,0 +1,353 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        shape::{Chain, Point, Rect, SignedDistance},
+        transform::Similarity,
+        Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Bezier, QuadBezier};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicBezier {
+    pub start: Point,
+    pub control1: Point,
+    pub control2: Point,
+    pub end: Point,
+}
+
+impl CubicBezier {
+    pub const fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    pub fn point_at(&self, t: Scalar) -> Point {
+        let s = 1.0 - t;
+        Point::from(
+            self.start.as_vector() * (s * s * s)
+                + self.control1.as_vector() * (3.0 * s * s * t)
+                + self.control2.as_vector() * (3.0 * s * t * t)
+                + self.end.as_vector() * (t * t * t),
+        )
+    }
+
+    pub fn derivative(&self, t: Scalar) -> Vector {
+        let s = 1.0 - t;
+        ((self.control1 - self.start) * (s * s)
+            + (self.control2 - self.control1) * (2.0 * s * t)
+            + (self.end - self.control2) * (t * t))
+            * 3.0
+    }
+
+    pub fn second_derivative(&self, t: Scalar) -> Vector {
+        let d1 = self.control1 - self.start;
+        let d2 = self.control2 - self.control1;
+        let d3 = self.end - self.control2;
+        ((d2 - d1) * (1.0 - t) + (d3 - d2) * t) * 6.0
+    }
+
+    pub fn subdivide(&self, t: Scalar) -> (CubicBezier, CubicBezier) {
+        let p01 = self.start + (self.control1 - self.start) * t;
+        let p12 = self.control1 + (self.control2 - self.control1) * t;
+        let p23 = self.control2 + (self.end - self.control2) * t;
+        let p012 = p01 + (p12 - p01) * t;
+        let p123 = p12 + (p23 - p12) * t;
+        let mid = p012 + (p123 - p012) * t;
+        (
+            CubicBezier::new(self.start, p01, p012, mid),
+            CubicBezier::new(mid, p123, p23, self.end),
+        )
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        // The derivative divided by 3 is a t² + b t + c
+        let d1 = self.control1 - self.start;
+        let d2 = self.control2 - self.control1;
+        let d3 = self.end - self.control2;
+        let a = d1 - d2 * 2.0 + d3;
+        let b = (d2 - d1) * 2.0;
+        let extremes = super::unit_quadratic_roots(a.x, b.x, d1.x)
+            .chain(super::unit_quadratic_roots(a.y, b.y, d1.y))
+            .map(|t| self.point_at(t));
+        Rect::from_points([self.start, self.end].into_iter().chain(extremes)).unwrap()
+    }
+
+    pub fn length(&self) -> Scalar {
+        super::length(self)
+    }
+
+    pub fn project(&self, point: Point) -> Scalar {
+        super::project(self, point)
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.point_at(self.project(point))
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        point.distance(self.closest_point(point))
+    }
+
+    // Chain whose segments stay within `tolerance` of the curve
+    pub fn flatten(&self, tolerance: Scalar) -> Chain {
+        super::flatten(self, tolerance)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.start *= t;
+        self.control1 *= t;
+        self.control2 *= t;
+        self.end *= t;
+    }
+}
+
+impl Bezier for CubicBezier {
+    fn control_points(&self) -> impl Iterator<Item = Point> {
+        [self.start, self.control1, self.control2, self.end].into_iter()
+    }
+
+    fn point_at(&self, t: Scalar) -> Point {
+        CubicBezier::point_at(self, t)
+    }
+
+    fn derivative(&self, t: Scalar) -> Vector {
+        CubicBezier::derivative(self, t)
+    }
+
+    fn second_derivative(&self, t: Scalar) -> Vector {
+        CubicBezier::second_derivative(self, t)
+    }
+
+    fn subdivide(&self, t: Scalar) -> (Self, Self) {
+        CubicBezier::subdivide(self, t)
+    }
+}
+
+impl SignedDistance for CubicBezier {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for CubicBezier {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.start.approx_eq(&other.start, epsilon)
+            && self.control1.approx_eq(&other.control1, epsilon)
+            && self.control2.approx_eq(&other.control2, epsilon)
+            && self.end.approx_eq(&other.end, epsilon)
+    }
+}
+
+impl Mul<CubicBezier> for &Similarity {
+    type Output = CubicBezier;
+
+    fn mul(self, mut rhs: CubicBezier) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for CubicBezier {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+// Degree elevation, which traces exactly the same curve
+impl From<QuadBezier> for CubicBezier {
+    fn from(value: QuadBezier) -> Self {
+        Self::new(
+            value.start,
+            value.start + (value.control - value.start) * (2.0 / 3.0),
+            value.end + (value.control - value.end) * (2.0 / 3.0),
+            value.end,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn curve() -> CubicBezier {
+        CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(3.0, 0.0),
+        )
+    }
+
+    // Brute force over a fine sampling of the curve
+    fn sampled_distance(curve: &CubicBezier, point: Point) -> Scalar {
+        (0..=100_000)
+            .map(|i| point.distance(curve.point_at(i as Scalar / 100_000.0)))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+
+    #[test]
+    fn point_at() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.point_at(0.0), Point::new(0.0, 0.0));
+        assert_approx_eq!(curve.point_at(0.5), Point::new(1.5, 1.5));
+        assert_approx_eq!(curve.point_at(1.0), Point::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn derivatives() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.derivative(0.0), vector!(0.0, 6.0));
+        assert_approx_eq!(curve.derivative(0.5), vector!(4.5, 0.0));
+        assert_approx_eq!(curve.derivative(1.0), vector!(0.0, -6.0));
+        assert_approx_eq!(curve.second_derivative(0.0), vector!(18.0, -12.0));
+        assert_approx_eq!(curve.second_derivative(1.0), vector!(-18.0, -12.0));
+    }
+
+    #[test]
+    fn subdivide() {
+        let curve = curve();
+        let (first, second) = curve.subdivide(0.4);
+
+        for i in 0..=10 {
+            let t = i as Scalar / 10.0;
+            assert_approx_eq!(
+                first.point_at(t),
+                curve.point_at(0.4 * t),
+                4.0 * Scalar::EPSILON
+            );
+            assert_approx_eq!(
+                second.point_at(t),
+                curve.point_at(0.4 + 0.6 * t),
+                8.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            curve().bounding_box(),
+            Rect::new(Point::new(0.0, 0.0), Point::new(3.0, 1.5))
+        );
+
+        let s_curve = CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(-2.0, 1.0),
+            Point::new(5.0, 1.0),
+            Point::new(3.0, 0.0),
+        );
+        let bounds = s_curve.bounding_box();
+        for i in 0..=1000 {
+            let p = s_curve.point_at(i as Scalar / 1000.0);
+            assert!(p.x() >= bounds.min.x() && p.x() <= bounds.max.x());
+            assert!(p.y() >= bounds.min.y() && p.y() <= bounds.max.y());
+        }
+        assert!(bounds.max.x() > 3.0 && bounds.min.x() < 0.0);
+    }
+
+    #[test]
+    fn length() {
+        let straight = CubicBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+            Point::new(4.0, 4.0),
+        );
+        assert_approx_eq!(straight.length(), Scalar::sqrt(32.0), 8.0 * Scalar::EPSILON);
+
+        let curve = curve();
+        let fine = curve.flatten(1e-7).length();
+        assert_approx_eq!(curve.length(), fine, 1e-6 + 256.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.distance_to_point(Point::new(1.5, 4.0)), 2.5);
+        for point in [
+            Point::new(1.5, 0.5),
+            Point::new(-1.0, 3.0),
+            Point::new(4.0, -1.0),
+            Point::new(0.5, 1.0),
+        ] {
+            assert_approx_eq!(
+                curve.distance_to_point(point),
+                sampled_distance(&curve, point),
+                1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn flatten() {
+        let curve = curve();
+        let tolerance = 0.001;
+        let chain = curve.flatten(tolerance);
+
+        assert_approx_eq!(chain.vertices[0], curve.start);
+        assert_approx_eq!(chain.vertices[chain.num_vertices() - 1], curve.end);
+        for segment in chain.segments() {
+            assert!(curve.distance_to_point(segment.point_at(0.5)) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn from_quad() {
+        let quad = QuadBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(2.0, 0.0),
+        );
+        let cubic = CubicBezier::from(quad.clone());
+
+        for i in 0..=10 {
+            let t = i as Scalar / 10.0;
+            assert_approx_eq!(cubic.point_at(t), quad.point_at(t), 2.0 * Scalar::EPSILON);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut curve = curve();
+        let t = Similarity::from_parts(0.5, FRAC_PI_2, vector!(1.0, 0.0));
+        let point = Point::new(1.5, 0.5);
+        let distance = curve.distance_to_point(point);
+
+        curve *= &t;
+        assert_approx_eq!(curve.start, Point::new(1.0, 0.0));
+        assert_approx_eq!(curve.end, Point::new(1.0, 1.5), 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(
+            curve.distance_to_point(&t * point),
+            0.5 * distance,
+            2.0 * Scalar::EPSILON
+        );
+    }
+
+    #[bench]
+    fn distance_to_point_bench(b: &mut Bencher) {
+        let curve = curve();
+        let point = Point::new(1.5, 0.5);
+
+        b.iter(|| black_box(curve.distance_to_point(point)));
+    }
+}
@@ -0,0 +1,177 @@
+mod cubic;
+mod quad;
+
+use crate::geometry::{Scalar, Vector};
+
+use super::{Chain, Point, Segment};
+
+pub use cubic::*;
+pub use quad::*;
+
+const PROJECTION_SAMPLES: usize = 32;
+const NEWTON_ITERATIONS: usize = 8;
+const MAX_SUBDIVISION_DEPTH: usize = 16;
+
+// Relative to the length of the control polygon
+const LENGTH_TOLERANCE: Scalar = 256.0 * Scalar::EPSILON;
+
+// Gauss-Legendre nodes and weights on [0, 1], rounded to the precision of `Scalar`
+const GAUSS_NODES: [Scalar; 5] = [
+    0.046_910_077_030_668_004_f64 as Scalar,
+    0.230_765_344_947_158_45_f64 as Scalar,
+    0.5,
+    0.769_234_655_052_841_5_f64 as Scalar,
+    0.953_089_922_969_332_f64 as Scalar,
+];
+const GAUSS_WEIGHTS: [Scalar; 5] = [
+    0.118_463_442_528_094_54_f64 as Scalar,
+    0.239_314_335_249_683_24_f64 as Scalar,
+    0.284_444_444_444_444_44_f64 as Scalar,
+    0.239_314_335_249_683_24_f64 as Scalar,
+    0.118_463_442_528_094_54_f64 as Scalar,
+];
+
+trait Bezier: Sized {
+    fn control_points(&self) -> impl Iterator<Item = Point>;
+
+    fn point_at(&self, t: Scalar) -> Point;
+
+    fn derivative(&self, t: Scalar) -> Vector;
+
+    fn second_derivative(&self, t: Scalar) -> Vector;
+
+    fn subdivide(&self, t: Scalar) -> (Self, Self);
+
+    fn start(&self) -> Point {
+        self.point_at(0.0)
+    }
+
+    fn end(&self) -> Point {
+        self.point_at(1.0)
+    }
+
+    // Upper bound on how far the curve strays from its chord
+    fn flatness(&self) -> Scalar {
+        let chord = Segment::new(self.start(), self.end());
+        self.control_points()
+            .map(|p| chord.distance_to_point(p))
+            .fold(0.0, Scalar::max)
+    }
+}
+
+fn length(curve: &impl Bezier) -> Scalar {
+    let mut control_points = curve.control_points();
+    let mut previous = control_points.next().unwrap();
+    let polygon_length: Scalar = control_points
+        .map(|p| {
+            let length = previous.distance(p);
+            previous = p;
+            length
+        })
+        .sum();
+    if polygon_length == 0.0 {
+        return 0.0;
+    }
+
+    let speed = |t| curve.derivative(t).norm();
+    let whole = gauss_legendre(&speed, 0.0, 1.0);
+    adaptive_length(
+        &speed,
+        0.0,
+        1.0,
+        whole,
+        LENGTH_TOLERANCE * polygon_length,
+        MAX_SUBDIVISION_DEPTH,
+    )
+}
+
+pub(super) fn gauss_legendre(f: &impl Fn(Scalar) -> Scalar, a: Scalar, b: Scalar) -> Scalar {
+    let width = b - a;
+    GAUSS_NODES
+        .iter()
+        .zip(GAUSS_WEIGHTS)
+        .map(|(node, weight)| weight * f(a + node * width))
+        .sum::<Scalar>()
+        * width
+}
+
+fn adaptive_length(
+    f: &impl Fn(Scalar) -> Scalar,
+    a: Scalar,
+    b: Scalar,
+    whole: Scalar,
+    tolerance: Scalar,
+    depth: usize,
+) -> Scalar {
+    let mid = (a + b) / 2.0;
+    let left = gauss_legendre(f, a, mid);
+    let right = gauss_legendre(f, mid, b);
+    if depth == 0 || (left + right - whole).abs() <= tolerance {
+        return left + right;
+    }
+
+    adaptive_length(f, a, mid, left, tolerance / 2.0, depth - 1)
+        + adaptive_length(f, mid, b, right, tolerance / 2.0, depth - 1)
+}
+
+// Parameter of the closest point, found by sampling and refined with Newton's method on the
+// derivative of the squared distance
+fn project(curve: &impl Bezier, point: Point) -> Scalar {
+    let distance_sq = |t| (curve.point_at(t) - point).norm_sq();
+    let mut best = (0..=PROJECTION_SAMPLES)
+        .map(|i| i as Scalar / PROJECTION_SAMPLES as Scalar)
+        .min_by(|&t1, &t2| distance_sq(t1).total_cmp(&distance_sq(t2)))
+        .unwrap();
+
+    let mut t = best;
+    for _ in 0..NEWTON_ITERATIONS {
+        let offset = curve.point_at(t) - point;
+        let derivative = curve.derivative(t);
+        let slope = offset.dot(derivative);
+        let curvature = derivative.norm_sq() + offset.dot(curve.second_derivative(t));
+        if curvature <= 0.0 {
+            break;
+        }
+
+        t = (t - slope / curvature).clamp(0.0, 1.0);
+        if distance_sq(t) < distance_sq(best) {
+            best = t;
+        }
+    }
+    best
+}
+
+fn flatten(curve: &impl Bezier, tolerance: Scalar) -> Chain {
+    assert!(tolerance > 0.0, "Flattening tolerance must be positive");
+
+    let mut points = vec![curve.start()];
+    flatten_into(curve, tolerance, MAX_SUBDIVISION_DEPTH, &mut points);
+    Chain::new(points)
+}
+
+fn flatten_into(curve: &impl Bezier, tolerance: Scalar, depth: usize, points: &mut Vec<Point>) {
+    if depth == 0 || curve.flatness() <= tolerance {
+        points.push(curve.end());
+        return;
+    }
+
+    let (first, second) = curve.subdivide(0.5);
+    flatten_into(&first, tolerance, depth - 1, points);
+    flatten_into(&second, tolerance, depth - 1, points);
+}
+
+// Roots in (0, 1) of a t² + b t + c
+fn unit_quadratic_roots(a: Scalar, b: Scalar, c: Scalar) -> impl Iterator<Item = Scalar> {
+    let roots = if a.abs() <= Scalar::EPSILON * (b.abs() + c.abs()) {
+        [(b != 0.0).then(|| -c / b), None]
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            [None, None]
+        } else {
+            let root = discriminant.sqrt();
+            [Some((-b - root) / (2.0 * a)), Some((-b + root) / (2.0 * a))]
+        }
+    };
+    roots.into_iter().flatten().filter(|t| *t > 0.0 && *t < 1.0)
+}
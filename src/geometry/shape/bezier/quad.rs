@@ -0,0 +1,260 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        shape::{Chain, Point, Rect, SignedDistance},
+        transform::Similarity,
+        Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::Bezier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadBezier {
+    pub start: Point,
+    pub control: Point,
+    pub end: Point,
+}
+
+impl QuadBezier {
+    pub const fn new(start: Point, control: Point, end: Point) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    pub fn point_at(&self, t: Scalar) -> Point {
+        let s = 1.0 - t;
+        Point::from(
+            self.start.as_vector() * (s * s)
+                + self.control.as_vector() * (2.0 * s * t)
+                + self.end.as_vector() * (t * t),
+        )
+    }
+
+    pub fn derivative(&self, t: Scalar) -> Vector {
+        ((self.control - self.start) * (1.0 - t) + (self.end - self.control) * t) * 2.0
+    }
+
+    pub fn second_derivative(&self) -> Vector {
+        (self.end - self.control - (self.control - self.start)) * 2.0
+    }
+
+    pub fn subdivide(&self, t: Scalar) -> (QuadBezier, QuadBezier) {
+        let c1 = self.start + (self.control - self.start) * t;
+        let c2 = self.control + (self.end - self.control) * t;
+        let mid = c1 + (c2 - c1) * t;
+        (
+            QuadBezier::new(self.start, c1, mid),
+            QuadBezier::new(mid, c2, self.end),
+        )
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        let a = self.start - self.control;
+        let b = a + (self.end - self.control);
+        let extremes = [(a.x, b.x), (a.y, b.y)]
+            .into_iter()
+            .filter(|&(_, denominator)| denominator != 0.0)
+            .map(|(numerator, denominator)| numerator / denominator)
+            .filter(|t| *t > 0.0 && *t < 1.0)
+            .map(|t| self.point_at(t));
+        Rect::from_points([self.start, self.end].into_iter().chain(extremes)).unwrap()
+    }
+
+    pub fn length(&self) -> Scalar {
+        super::length(self)
+    }
+
+    pub fn project(&self, point: Point) -> Scalar {
+        super::project(self, point)
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.point_at(self.project(point))
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        point.distance(self.closest_point(point))
+    }
+
+    // Chain whose segments stay within `tolerance` of the curve
+    pub fn flatten(&self, tolerance: Scalar) -> Chain {
+        super::flatten(self, tolerance)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.start *= t;
+        self.control *= t;
+        self.end *= t;
+    }
+}
+
+impl Bezier for QuadBezier {
+    fn control_points(&self) -> impl Iterator<Item = Point> {
+        [self.start, self.control, self.end].into_iter()
+    }
+
+    fn point_at(&self, t: Scalar) -> Point {
+        QuadBezier::point_at(self, t)
+    }
+
+    fn derivative(&self, t: Scalar) -> Vector {
+        QuadBezier::derivative(self, t)
+    }
+
+    fn second_derivative(&self, _: Scalar) -> Vector {
+        QuadBezier::second_derivative(self)
+    }
+
+    fn subdivide(&self, t: Scalar) -> (Self, Self) {
+        QuadBezier::subdivide(self, t)
+    }
+}
+
+impl SignedDistance for QuadBezier {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for QuadBezier {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.start.approx_eq(&other.start, epsilon)
+            && self.control.approx_eq(&other.control, epsilon)
+            && self.end.approx_eq(&other.end, epsilon)
+    }
+}
+
+impl Mul<QuadBezier> for &Similarity {
+    type Output = QuadBezier;
+
+    fn mul(self, mut rhs: QuadBezier) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for QuadBezier {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn curve() -> QuadBezier {
+        QuadBezier::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(2.0, 0.0),
+        )
+    }
+
+    #[test]
+    fn point_at() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.point_at(0.0), Point::new(0.0, 0.0));
+        assert_approx_eq!(curve.point_at(0.5), Point::new(1.0, 1.0));
+        assert_approx_eq!(curve.point_at(1.0), Point::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn derivatives() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.derivative(0.0), vector!(2.0, 4.0));
+        assert_approx_eq!(curve.derivative(0.5), vector!(2.0, 0.0));
+        assert_approx_eq!(curve.second_derivative(), vector!(0.0, -8.0));
+    }
+
+    #[test]
+    fn subdivide() {
+        let curve = curve();
+        let (first, second) = curve.subdivide(0.25);
+
+        for i in 0..=10 {
+            let t = i as Scalar / 10.0;
+            assert_approx_eq!(first.point_at(t), curve.point_at(0.25 * t));
+            assert_approx_eq!(
+                second.point_at(t),
+                curve.point_at(0.25 + 0.75 * t),
+                2.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            curve().bounding_box(),
+            Rect::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn length() {
+        // Closed form for the parabola y = 2x - x²
+        let expected = Scalar::sqrt(5.0) + Scalar::asinh(2.0) / 2.0;
+        assert_approx_eq!(curve().length(), expected);
+
+        let straight = QuadBezier::new(Point::ORIGIN, Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert_approx_eq!(straight.length(), Scalar::sqrt(18.0), 8.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let curve = curve();
+
+        assert_approx_eq!(curve.distance_to_point(Point::new(1.0, 3.0)), 2.0);
+        assert_approx_eq!(curve.project(Point::new(1.0, 3.0)), 0.5);
+        assert_approx_eq!(
+            curve.distance_to_point(Point::new(-1.0, -1.0)),
+            Scalar::sqrt(2.0)
+        );
+        assert_approx_eq!(curve.distance_to_point(curve.point_at(0.3)), 0.0);
+    }
+
+    #[test]
+    fn flatten() {
+        let curve = curve();
+        let tolerance = 0.001;
+        let chain = curve.flatten(tolerance);
+
+        assert_approx_eq!(chain.vertices[0], curve.start);
+        assert_approx_eq!(chain.vertices[chain.num_vertices() - 1], curve.end);
+        for segment in chain.segments() {
+            assert!(curve.distance_to_point(segment.point_at(0.5)) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut curve = curve();
+        curve *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+
+        assert_approx_eq!(
+            curve,
+            QuadBezier::new(
+                Point::new(1.0, 0.0),
+                Point::new(-3.0, 2.0),
+                Point::new(1.0, 4.0)
+            ),
+            8.0 * Scalar::EPSILON
+        );
+    }
+}
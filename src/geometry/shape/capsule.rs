@@ -0,0 +1,174 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{Circle, Point, Ray, Rect, Segment, SignedDistance};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Capsule {
+    pub segment: Segment,
+    pub radius: Scalar,
+}
+
+impl Capsule {
+    pub const fn new(segment: Segment, radius: Scalar) -> Self {
+        Self { segment, radius }
+    }
+
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        self.segment.distance_to_point(point) - self.radius
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        Rect::from_points([self.segment.start, self.segment.end])
+            .unwrap()
+            .expand(self.radius)
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        let direction = self.segment.as_vector();
+        let length = direction.norm();
+        if length == 0.0 {
+            return Circle::new(self.segment.start, self.radius).cast_ray(ray);
+        }
+
+        // The boundary is made of two straight sides and the outer halves of the end circles
+        let offset = direction.perp() * (self.radius / length);
+        let sides = [
+            Segment::new(self.segment.start + offset, self.segment.end + offset),
+            Segment::new(self.segment.start - offset, self.segment.end - offset),
+        ];
+        let side_hits = sides.iter().filter_map(|s| ray.segment_intersection(s));
+
+        let caps = [(self.segment.start, -1.0), (self.segment.end, 1.0)];
+        let cap_hits = caps.into_iter().flat_map(|(center, outward)| {
+            let offset = ray.origin - center;
+            let a = ray.direction.norm_sq();
+            let b = offset.dot(ray.direction);
+            let c = offset.norm_sq() - self.radius * self.radius;
+            let discriminant = b * b - a * c;
+            let root = discriminant.max(0.0).sqrt();
+            [(-b - root) / a, (-b + root) / a]
+                .into_iter()
+                .filter(move |&t| a > 0.0 && discriminant >= 0.0 && t >= 0.0)
+                .filter(move |&t| (ray.point_at(t) - center).dot(direction) * outward >= 0.0)
+        });
+
+        side_hits.chain(cap_hits).min_by(Scalar::total_cmp)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.segment *= t;
+        self.radius *= t.scale();
+    }
+}
+
+impl SignedDistance for Capsule {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Capsule::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for Capsule {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.segment.approx_eq(&other.segment, epsilon)
+            && self.radius.approx_eq(&other.radius, epsilon)
+    }
+}
+
+impl Mul<Capsule> for &Similarity {
+    type Output = Capsule;
+
+    fn mul(self, mut rhs: Capsule) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Capsule {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar, vector, Vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn capsule() -> Capsule {
+        Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        )
+    }
+
+    #[test]
+    fn signed_distance_side() {
+        assert_approx_eq!(capsule().signed_distance(Point::new(2.0, 3.0)), 2.0);
+    }
+
+    #[test]
+    fn signed_distance_cap() {
+        assert_approx_eq!(capsule().signed_distance(Point::new(7.0, 4.0)), 4.0);
+    }
+
+    #[test]
+    fn signed_distance_inside() {
+        assert_approx_eq!(capsule().signed_distance(Point::new(1.0, 0.5)), -0.5);
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            capsule().bounding_box(),
+            Rect::new(Point::new(-1.0, -1.0), Point::new(5.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn cast_ray() {
+        let capsule = capsule();
+
+        let side = Ray::new(Point::new(2.0, 3.0), -Vector::Y);
+        assert_approx_eq!(capsule.cast_ray(&side).unwrap(), 2.0);
+
+        let cap = Ray::new(Point::new(-3.0, 0.0), vector!(2.0, 0.0));
+        assert_approx_eq!(capsule.cast_ray(&cap).unwrap(), 1.0);
+
+        let inside = Ray::new(Point::new(2.0, 0.0), Vector::X);
+        assert_approx_eq!(capsule.cast_ray(&inside).unwrap(), 3.0);
+
+        let diagonal = Ray::new(Point::new(6.0, 2.0), vector!(-1.0, -1.0));
+        assert_approx_eq!(
+            capsule.cast_ray(&diagonal).unwrap(),
+            2.0 - scalar::consts::FRAC_1_SQRT_2
+        );
+
+        let miss = Ray::new(Point::new(-2.0, 1.5), Vector::X);
+        assert_eq!(capsule.cast_ray(&miss), None);
+    }
+
+    #[test]
+    fn transform() {
+        let mut capsule = capsule();
+        capsule *= &Similarity::from_parts(2.0, scalar::consts::TAU / 4.0, vector!(2.0, -1.0));
+        assert_approx_eq!(
+            capsule,
+            Capsule::new(
+                Segment::new(Point::new(2.0, -1.0), Point::new(2.0, 7.0)),
+                2.0
+            ),
+            8.0 * Scalar::EPSILON
+        );
+    }
+}
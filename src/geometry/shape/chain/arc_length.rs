@@ -0,0 +1,247 @@
+use crate::geometry::{shape::Point, Scalar, Vector};
+
+use super::Chain;
+
+impl Chain {
+    pub fn length(&self) -> Scalar {
+        self.segments().map(|s| s.length()).sum()
+    }
+
+    pub fn point_at_distance(&self, distance: Scalar) -> Option<(Point, Vector)> {
+        self.point_at_cumulative_distance(&self.cumulative_lengths(), distance)
+    }
+
+    pub fn resample(&self, num_points: usize) -> Chain {
+        if num_points < 2 || self.num_vertices() < 2 {
+            return self.vertices().take(num_points).collect();
+        }
+
+        let cumulative = self.cumulative_lengths();
+        let step = cumulative[cumulative.len() - 1] / (num_points - 1) as Scalar;
+        let mut points: Vec<Point> = (0..num_points - 1)
+            .map(|i| {
+                self.point_at_cumulative_distance(&cumulative, step * i as Scalar)
+                    .map_or(self.vertices[0], |(point, _)| point)
+            })
+            .collect();
+        points.push(self.vertices[self.num_vertices() - 1]);
+        Chain::new(points)
+    }
+
+    pub fn densify(&self, max_segment_length: Scalar) -> Chain {
+        assert!(
+            max_segment_length > 0.0,
+            "Maximum segment length must be positive"
+        );
+
+        let mut points = Vec::with_capacity(self.num_vertices());
+        for segment in self.segments() {
+            let parts = (segment.length() / max_segment_length).ceil().max(1.0) as usize;
+            let step = segment.as_vector() / parts as Scalar;
+            points.extend((0..parts).map(|i| segment.start + step * i as Scalar));
+        }
+        points.extend(self.vertices().last());
+        Chain::new(points)
+    }
+
+    pub(super) fn cumulative_lengths(&self) -> Vec<Scalar> {
+        if self.vertices.is_empty() {
+            return Vec::new();
+        }
+
+        let mut total = 0.0;
+        let mut lengths = Vec::with_capacity(self.num_vertices());
+        lengths.push(0.0);
+        lengths.extend(self.segments().map(|s| {
+            total += s.length();
+            total
+        }));
+        lengths
+    }
+
+    pub(super) fn point_at_cumulative_distance(
+        &self,
+        cumulative: &[Scalar],
+        distance: Scalar,
+    ) -> Option<(Point, Vector)> {
+        let total = *cumulative.last()?;
+        if total <= 0.0 {
+            return None;
+        }
+
+        let distance = distance.clamp(0.0, total);
+        let first_nonempty = cumulative.partition_point(|&l| l <= 0.0);
+        let end = cumulative
+            .partition_point(|&l| l < distance)
+            .max(first_nonempty);
+
+        let start_distance = cumulative[end - 1];
+        let segment_vector = self.vertices[end] - self.vertices[end - 1];
+        let tangent = segment_vector / (cumulative[end] - start_distance);
+        Some((
+            self.vertices[end - 1] + tangent * (distance - start_distance),
+            tangent,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{geometry::shape::Segment, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    fn l_shape() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn length() {
+        assert_approx_eq!(l_shape().length(), 7.0);
+        assert_approx_eq!(Chain::from(Point::new(1.0, 1.0)).length(), 0.0);
+    }
+
+    #[test]
+    fn point_at_distance() {
+        let chain = l_shape();
+
+        let (point, tangent) = chain.point_at_distance(1.5).unwrap();
+        assert_approx_eq!(point, Point::new(1.5, 0.0));
+        assert_approx_eq!(tangent, Vector::X);
+
+        let (point, tangent) = chain.point_at_distance(5.0).unwrap();
+        assert_approx_eq!(point, Point::new(3.0, 2.0));
+        assert_approx_eq!(tangent, Vector::Y);
+    }
+
+    #[test]
+    fn point_at_distance_at_vertices() {
+        let chain = l_shape();
+
+        let (point, tangent) = chain.point_at_distance(0.0).unwrap();
+        assert_approx_eq!(point, Point::new(0.0, 0.0));
+        assert_approx_eq!(tangent, Vector::X);
+
+        let (point, tangent) = chain.point_at_distance(3.0).unwrap();
+        assert_approx_eq!(point, Point::new(3.0, 0.0));
+        assert_approx_eq!(tangent, Vector::X);
+
+        let (point, tangent) = chain.point_at_distance(7.0).unwrap();
+        assert_approx_eq!(point, Point::new(3.0, 4.0));
+        assert_approx_eq!(tangent, Vector::Y);
+    }
+
+    #[test]
+    fn point_at_distance_clamped() {
+        let chain = l_shape();
+
+        let (point, _) = chain.point_at_distance(-1.0).unwrap();
+        assert_approx_eq!(point, Point::new(0.0, 0.0));
+
+        let (point, _) = chain.point_at_distance(10.0).unwrap();
+        assert_approx_eq!(point, Point::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn point_at_distance_skips_degenerate_segments() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 2.0),
+        ]);
+
+        let (point, tangent) = chain.point_at_distance(0.0).unwrap();
+        assert_approx_eq!(point, Point::new(0.0, 0.0));
+        assert_approx_eq!(tangent, Vector::Y);
+    }
+
+    #[test]
+    fn point_at_distance_without_length() {
+        assert_eq!(Chain::new(vec![]).point_at_distance(0.0), None);
+        assert_eq!(
+            Chain::from(Point::new(1.0, 1.0)).point_at_distance(0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn resample() {
+        assert_approx_eq!(
+            l_shape().resample(8),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.0, 0.0),
+                Point::new(2.0, 0.0),
+                Point::new(3.0, 0.0),
+                Point::new(3.0, 1.0),
+                Point::new(3.0, 2.0),
+                Point::new(3.0, 3.0),
+                Point::new(3.0, 4.0),
+            ]),
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn resample_few_points() {
+        assert_approx_eq!(l_shape().resample(0), Chain::new(vec![]));
+        assert_approx_eq!(l_shape().resample(1), Chain::from(Point::new(0.0, 0.0)));
+        assert_approx_eq!(
+            l_shape().resample(2),
+            Chain::new(vec![Point::new(0.0, 0.0), Point::new(3.0, 4.0)])
+        );
+    }
+
+    #[test]
+    fn densify() {
+        assert_approx_eq!(
+            l_shape().densify(1.5),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(1.5, 0.0),
+                Point::new(3.0, 0.0),
+                Point::new(3.0, 4.0 / 3.0),
+                Point::new(3.0, 8.0 / 3.0),
+                Point::new(3.0, 4.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn densify_keeps_short_segments() {
+        let chain = l_shape();
+        assert_approx_eq!(chain.densify(10.0), chain);
+        assert_approx_eq!(
+            Chain::from(Point::new(1.0, 1.0)).densify(1.0),
+            Chain::from(Point::new(1.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn densify_keeps_segment_direction() {
+        let chain = Chain::from(Segment::new(Point::new(1.0, 1.0), Point::new(-1.0, 1.0)));
+        assert_approx_eq!(
+            chain.densify(1.0),
+            Chain::new(vec![
+                Point::new(1.0, 1.0),
+                Point::new(0.0, 1.0),
+                Point::new(-1.0, 1.0),
+            ])
+        );
+    }
+
+    #[bench]
+    fn resample_1000_points_bench(b: &mut Bencher) {
+        let chain: Chain = (0..100)
+            .map(|i| Point::new(i as Scalar, (i % 2) as Scalar))
+            .collect();
+
+        b.iter(|| black_box(chain.resample(1000)));
+    }
+}
@@ -0,0 +1,434 @@
+mod arc_length;
+mod offset;
+mod projection;
+mod registration;
+mod simplify;
+
+use std::{
+    iter::{self, Copied, Peekable},
+    ops::{Mul, MulAssign},
+    slice,
+};
+
+use crate::{
+    geometry::{
+        transform::{Affine, HomogeneousPoint, Homography, Similarity},
+        Scalar,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Point, Segment, SignedDistance};
+
+pub use offset::*;
+pub use projection::*;
+pub use registration::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chain {
+    pub vertices: Vec<Point>,
+}
+
+impl Chain {
+    pub const fn new(vertices: Vec<Point>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> Vertices<'_> {
+        self.vertices.iter().copied()
+    }
+
+    pub fn vertices_mut(&mut self) -> VerticesMut<'_> {
+        self.vertices.iter_mut()
+    }
+
+    pub fn segments(&self) -> Segments<'_> {
+        Segments {
+            vertices: self.vertices.iter().peekable(),
+        }
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn num_segments(&self) -> usize {
+        self.vertices.len().saturating_sub(1)
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.num_vertices() > 3 && self.vertices.first() == self.vertices.last()
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        self.segments()
+            .map(|s| s.distance_to_point(point))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+
+    // Positive for counterclockwise rings. Open chains are treated as if closed.
+    pub fn signed_area(&self) -> Scalar {
+        let closing = match (self.vertices.first(), self.vertices.last()) {
+            (Some(&first), Some(&last)) => last.as_vector().perp_dot(first.as_vector()),
+            _ => 0.0,
+        };
+        let edges: Scalar = self
+            .segments()
+            .map(|s| s.start.as_vector().perp_dot(s.end.as_vector()))
+            .sum();
+        (edges + closing) / 2.0
+    }
+
+    // Number of counterclockwise turns the closed chain makes around the point. Crossings are
+    // counted half-open in y so that vertices on the ray are counted once.
+    pub fn winding_number(&self, point: Point) -> i32 {
+        self.segments().map(|s| crossing(&s, point)).sum()
+    }
+
+    // Number of times a ray from the point crosses the closed chain, whatever the direction
+    pub fn crossing_number(&self, point: Point) -> usize {
+        self.segments().filter(|s| crossing(s, point) != 0).count()
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.vertices_mut().for_each(|v| *v *= t);
+    }
+}
+
+impl SignedDistance for Chain {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for Chain {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        if self.num_vertices() != other.num_vertices() {
+            return false;
+        }
+        for (v1, v2) in iter::zip(self.vertices(), other.vertices()) {
+            if v1.approx_ne(&v2, epsilon) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Mul<Chain> for &Similarity {
+    type Output = Chain;
+
+    fn mul(self, mut rhs: Chain) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Chain {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl Mul<Chain> for &Affine {
+    type Output = Chain;
+
+    fn mul(self, mut rhs: Chain) -> Self::Output {
+        rhs.vertices_mut().for_each(|v| *v = self * *v);
+        rhs
+    }
+}
+
+// None when the chain touches the line sent to infinity, as its image is then unbounded
+impl Mul<Chain> for &Homography {
+    type Output = Option<Chain>;
+
+    fn mul(self, rhs: Chain) -> Self::Output {
+        let mapped: Vec<HomogeneousPoint> = rhs
+            .vertices()
+            .map(|v| self.apply_homogeneous(v.as_vector().into()))
+            .collect();
+        let side = mapped.first().map_or(1.0, |p| p.w.signum());
+        if mapped.iter().any(|p| p.w * side <= 0.0) {
+            return None;
+        }
+        mapped
+            .into_iter()
+            .map(|p| p.to_vector().map(Point::from))
+            .collect()
+    }
+}
+
+impl From<Point> for Chain {
+    fn from(value: Point) -> Self {
+        Self::new(vec![value])
+    }
+}
+
+impl From<Segment> for Chain {
+    fn from(value: Segment) -> Self {
+        Self::new(vec![value.start, value.end])
+    }
+}
+
+impl FromIterator<Point> for Chain {
+    fn from_iter<T: IntoIterator<Item = Point>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+pub type Vertices<'a> = Copied<slice::Iter<'a, Point>>;
+pub type VerticesMut<'a> = slice::IterMut<'a, Point>;
+
+pub struct Segments<'a> {
+    vertices: Peekable<slice::Iter<'a, Point>>,
+}
+
+impl<'a> Iterator for Segments<'a> {
+    type Item = Segment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.vertices.next()?;
+        let end = **self.vertices.peek()?;
+        Some(Segment::new(start, end))
+    }
+}
+
+impl<'a> ExactSizeIterator for Segments<'a> {
+    fn len(&self) -> usize {
+        self.vertices.len().saturating_sub(1)
+    }
+}
+
+// +1 for an upward crossing of the ray to the right of the point, -1 for a downward one
+fn crossing(segment: &Segment, point: Point) -> i32 {
+    let side = segment.as_vector().perp_dot(point - segment.start);
+    if segment.start.y() <= point.y() && segment.end.y() > point.y() && side > 0.0 {
+        1
+    } else if segment.end.y() <= point.y() && segment.start.y() > point.y() && side < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar, transform::Translation, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    #[test]
+    fn vertices() {
+        let chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        let mut vertices = chain.vertices();
+        assert_eq!(vertices.next(), Some(Point::new(1.0, 2.0)));
+        assert_eq!(vertices.next(), Some(Point::new(3.0, 2.0)));
+        assert_eq!(vertices.next(), Some(Point::new(4.0, 3.0)));
+        assert_eq!(vertices.next(), None);
+    }
+
+    #[test]
+    fn vertices_mut() {
+        let mut chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        let mut vertices = chain.vertices_mut();
+        assert_eq!(vertices.next(), Some(&mut Point::new(1.0, 2.0)));
+        assert_eq!(vertices.next(), Some(&mut Point::new(3.0, 2.0)));
+        assert_eq!(vertices.next(), Some(&mut Point::new(4.0, 3.0)));
+        assert_eq!(vertices.next(), None);
+    }
+
+    #[test]
+    fn segments() {
+        let chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        let mut segments = chain.segments();
+        assert_eq!(
+            segments.next(),
+            Some(Segment::new(Point::new(1.0, 2.0), Point::new(3.0, 2.0)))
+        );
+        assert_eq!(
+            segments.next(),
+            Some(Segment::new(Point::new(3.0, 2.0), Point::new(4.0, 3.0)))
+        );
+        assert_eq!(segments.next(), None);
+    }
+
+    #[test]
+    fn lengths() {
+        let chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        assert_eq!(chain.num_vertices(), 3);
+        assert_eq!(chain.num_segments(), 2);
+    }
+
+    #[test]
+    fn is_closed() {
+        let mut chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+        assert!(!chain.is_closed());
+
+        chain.vertices.push(Point::new(1.0, 2.0));
+        assert!(chain.is_closed());
+
+        let out_and_back = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(1.0, 2.0),
+        ]);
+        assert!(!out_and_back.is_closed());
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        assert_approx_eq!(
+            chain.distance_to_point(Point::new(3.0, 3.0)),
+            scalar::consts::FRAC_1_SQRT_2
+        );
+    }
+
+    #[test]
+    fn signed_area() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert_approx_eq!(square.signed_area(), 4.0);
+
+        let open: Chain = square.vertices().take(4).collect();
+        assert_approx_eq!(open.signed_area(), 4.0);
+
+        let reversed: Chain = square.vertices().rev().collect();
+        assert_approx_eq!(reversed.signed_area(), -4.0);
+    }
+
+    #[test]
+    fn winding_number() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert_eq!(square.winding_number(Point::new(1.0, 1.0)), 1);
+        assert_eq!(square.winding_number(Point::new(3.0, 1.0)), 0);
+        // The ray passes exactly through a vertex
+        assert_eq!(square.winding_number(Point::new(-1.0, 2.0)), 0);
+        assert_eq!(square.winding_number(Point::new(-1.0, 0.0)), 0);
+
+        let reversed: Chain = square.vertices().rev().collect();
+        assert_eq!(reversed.winding_number(Point::new(1.0, 1.0)), -1);
+        assert_eq!(reversed.crossing_number(Point::new(1.0, 1.0)), 1);
+        assert_eq!(reversed.crossing_number(Point::new(-1.0, 1.0)), 2);
+    }
+
+    #[test]
+    fn transform() {
+        let mut chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+
+        chain *= &Translation::from(vector!(1.0, 0.0)).into();
+        assert_approx_eq!(
+            chain,
+            Chain::new(vec![
+                Point::new(2.0, 2.0),
+                Point::new(4.0, 2.0),
+                Point::new(5.0, 3.0)
+            ])
+        )
+    }
+
+    #[test]
+    fn affine_transform() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        let affine = Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+
+        let mapped = &affine * square.clone();
+        assert_approx_eq!(mapped.vertices[2], Point::new(9.0, -1.0));
+        assert_approx_eq!(
+            mapped.signed_area(),
+            affine.determinant() * square.signed_area()
+        );
+    }
+
+    #[test]
+    fn mirror_flips_winding() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        let mirror = Similarity::from_reflection(0.0);
+
+        let mirrored = &mirror * square.clone();
+        assert_approx_eq!(mirrored.signed_area(), -square.signed_area());
+        assert_eq!(mirrored.winding_number(Point::new(1.0, -1.0)), -1);
+    }
+
+    #[test]
+    fn homography_transform() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        let perspective = Homography::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.5, 1.0]]);
+
+        let mapped = (&perspective * square.clone()).unwrap();
+        assert!(mapped.is_closed());
+        assert_approx_eq!(mapped.vertices[2], Point::new(1.0, 1.0));
+        assert_approx_eq!(mapped.vertices[3], Point::new(0.0, 1.0));
+
+        let below: Chain = square.vertices().map(|v| v - vector!(0.0, 3.0)).collect();
+        assert_eq!(&perspective * below, None);
+    }
+}
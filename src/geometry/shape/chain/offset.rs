@@ -0,0 +1,751 @@
+use crate::geometry::{
+    scalar::consts::{FRAC_PI_2, PI, TAU},
+    shape::{chain_intersections, Point, Segment},
+    transform::ScaledRotation,
+    Scalar, Vector,
+};
+
+use super::Chain;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle {
+    Miter(Scalar),
+    Round,
+    Bevel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CapStyle {
+    Butt,
+    Round,
+    Square,
+}
+
+const ARC_SEGMENTS_PER_QUARTER: usize = 8;
+
+impl Chain {
+    pub fn offset(&self, distance: Scalar, join: JoinStyle) -> Vec<Chain> {
+        let vertices = self.distinct_vertices();
+        if vertices.len() < 2 {
+            return Vec::new();
+        }
+        if distance == 0.0 {
+            return vec![Chain::new(vertices)];
+        }
+
+        // The offset is the part of the buffer boundary that lies on the requested side
+        let side = if distance < 0.0 {
+            Side::Right
+        } else {
+            Side::Left
+        };
+        let band = outline(&vertices, distance.abs(), join, CapStyle::Butt, side);
+        let tolerance = Scalar::EPSILON.sqrt() * distance.abs();
+        let mut pieces = trim(&band, tolerance);
+        if side == Side::Left {
+            pieces.reverse();
+            pieces.iter_mut().for_each(|piece| piece.reverse());
+        }
+
+        if is_ring(&vertices) {
+            stitch(pieces, tolerance)
+        } else {
+            join_pieces(pieces, tolerance)
+        }
+    }
+
+    pub fn buffer(&self, distance: Scalar, join: JoinStyle, cap: CapStyle) -> Vec<Chain> {
+        if distance <= 0.0 {
+            return Vec::new();
+        }
+
+        let vertices = self.distinct_vertices();
+        match vertices.len() {
+            0 => Vec::new(),
+            1 => point_buffer(vertices[0], distance, cap)
+                .into_iter()
+                .collect(),
+            _ => {
+                let band = outline(&vertices, distance, join, cap, Side::Both);
+                let tolerance = Scalar::EPSILON.sqrt() * distance;
+                stitch(trim(&band, tolerance), tolerance)
+            }
+        }
+    }
+
+    fn distinct_vertices(&self) -> Vec<Point> {
+        let mut vertices = self.vertices.clone();
+        vertices.dedup();
+        vertices
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+    Both,
+}
+
+// The counterclockwise outline of the buffer around `vertices`, made up of the right offset
+// and the right offset of the reversed chain, i.e. the left offset walked backwards.
+fn outline(
+    vertices: &[Point],
+    distance: Scalar,
+    join: JoinStyle,
+    cap: CapStyle,
+    side: Side,
+) -> Vec<BandRing> {
+    let reversed: Vec<Point> = vertices.iter().rev().copied().collect();
+    let closed = is_ring(vertices);
+    let right = offset_vertices(vertices, -distance, join, closed);
+    let left = offset_vertices(&reversed, -distance, join, closed);
+
+    // The detours at inner corners cut across the band and are never part of an offset
+    let candidate = |(points, detours): &(Vec<Point>, Vec<usize>), ring_side: Side| {
+        let mut candidate = vec![side == Side::Both || side == ring_side; points.len() - 1];
+        if side != Side::Both {
+            for &detour in detours {
+                candidate[detour - 1] = false;
+                candidate[detour] = false;
+            }
+        }
+        candidate
+    };
+    let (right_candidate, left_candidate) =
+        (candidate(&right, Side::Right), candidate(&left, Side::Left));
+    let ((right, _), (left, _)) = (right, left);
+
+    if closed {
+        return vec![
+            BandRing::new(right, right_candidate),
+            BandRing::new(left, left_candidate),
+        ];
+    }
+
+    let n = vertices.len();
+    let end_direction = (vertices[n - 1] - vertices[n - 2]).normalize();
+    let start_direction = (vertices[0] - vertices[1]).normalize();
+
+    let mut ring = right;
+    let mut candidate = right_candidate;
+    cap_vertices(vertices[n - 1], end_direction, distance, cap, &mut ring);
+    candidate.resize(ring.len(), side == Side::Both);
+    ring.extend(left);
+    candidate.extend(left_candidate);
+    cap_vertices(vertices[0], start_direction, distance, cap, &mut ring);
+    ring.push(ring[0]);
+    candidate.resize(ring.len() - 1, side == Side::Both);
+    vec![BandRing::new(ring, candidate)]
+}
+
+fn is_ring(vertices: &[Point]) -> bool {
+    Chain::new(vertices.to_vec()).is_closed()
+}
+
+// Also returns the indices of the chain vertices inserted as detours at inner corners.
+fn offset_vertices(
+    vertices: &[Point],
+    distance: Scalar,
+    join: JoinStyle,
+    closed: bool,
+) -> (Vec<Point>, Vec<usize>) {
+    let directions: Vec<Vector> = vertices
+        .windows(2)
+        .map(|w| (w[1] - w[0]).normalize())
+        .collect();
+    let last = directions.len() - 1;
+
+    let mut offset = Vec::with_capacity(2 * vertices.len());
+    let mut detours = Vec::new();
+    let mut join = |i: usize, incoming: Vector, offset: &mut Vec<Point>| {
+        if join_vertices(vertices[i], incoming, directions[i], distance, join, offset) {
+            detours.push(offset.len() - 2);
+        }
+    };
+    if closed {
+        join(0, directions[last], &mut offset);
+    } else {
+        offset.push(vertices[0] + directions[0].perp() * distance);
+    }
+    for i in 1..directions.len() {
+        join(i, directions[i - 1], &mut offset);
+    }
+    if closed {
+        offset.push(offset[0]);
+    } else {
+        offset.push(vertices[last + 1] + directions[last].perp() * distance);
+    }
+    (offset, detours)
+}
+
+fn join_vertices(
+    vertex: Point,
+    incoming: Vector,
+    outgoing: Vector,
+    distance: Scalar,
+    join: JoinStyle,
+    out: &mut Vec<Point>,
+) -> bool {
+    let n1 = incoming.perp() * distance;
+    let n2 = outgoing.perp() * distance;
+    let cross = incoming.perp_dot(outgoing);
+    let dot = incoming.dot(outgoing);
+
+    if cross == 0.0 && dot > 0.0 {
+        out.push(vertex + n1);
+        return false;
+    }
+
+    if cross * distance > 0.0 {
+        // Inner corner. The detour through the vertex makes sure that the loop formed by
+        // the overlapping offset segments winds the same way as the rest of the outline.
+        out.extend([vertex + n1, vertex, vertex + n2]);
+        return true;
+    }
+
+    match join {
+        JoinStyle::Miter(limit) if dot > -1.0 && 2.0 / (1.0 + dot) <= limit * limit => {
+            out.push(vertex + (n1 + n2) / (1.0 + dot));
+        }
+        JoinStyle::Round => {
+            let angle = if cross == 0.0 {
+                -PI.copysign(distance)
+            } else {
+                n1.perp_dot(n2).atan2(n1.dot(n2))
+            };
+            out.extend(arc(vertex, n1, angle));
+        }
+        _ => out.extend([vertex + n1, vertex + n2]),
+    }
+    false
+}
+
+fn cap_vertices(
+    vertex: Point,
+    direction: Vector,
+    distance: Scalar,
+    cap: CapStyle,
+    out: &mut Vec<Point>,
+) {
+    let normal = direction.perp() * distance;
+    match cap {
+        CapStyle::Butt => {}
+        CapStyle::Square => out.extend([
+            vertex - normal + direction * distance,
+            vertex + normal + direction * distance,
+        ]),
+        CapStyle::Round => {
+            let arc = arc(vertex, -normal, PI);
+            out.extend(&arc[1..arc.len() - 1]);
+        }
+    }
+}
+
+fn point_buffer(point: Point, distance: Scalar, cap: CapStyle) -> Option<Chain> {
+    match cap {
+        CapStyle::Butt => None,
+        CapStyle::Square => Some(Chain::new(vec![
+            Point::new(point.x() - distance, point.y() - distance),
+            Point::new(point.x() + distance, point.y() - distance),
+            Point::new(point.x() + distance, point.y() + distance),
+            Point::new(point.x() - distance, point.y() + distance),
+            Point::new(point.x() - distance, point.y() - distance),
+        ])),
+        CapStyle::Round => {
+            let mut circle = arc(point, Vector::X * distance, TAU);
+            circle.pop();
+            circle.push(circle[0]);
+            Some(Chain::new(circle))
+        }
+    }
+}
+
+fn arc(center: Point, from: Vector, angle: Scalar) -> Vec<Point> {
+    let steps = (angle.abs() / FRAC_PI_2 * ARC_SEGMENTS_PER_QUARTER as Scalar)
+        .ceil()
+        .max(1.0) as usize;
+    (0..=steps)
+        .map(|k| {
+            center + ScaledRotation::from_parts(1.0, angle * k as Scalar / steps as Scalar) * from
+        })
+        .collect()
+}
+
+// A closed ring bounding part of the region covered by an offset or buffer, with the
+// covered region on its left. Only the segments marked as candidates may end up in the result.
+struct BandRing {
+    points: Vec<Point>,
+    candidate: Vec<bool>,
+}
+
+impl BandRing {
+    fn new(points: Vec<Point>, candidate: Vec<bool>) -> Self {
+        Self { points, candidate }
+    }
+
+    fn num_segments(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    fn winding_number(&self, point: Point) -> i32 {
+        Chain::new(self.points.clone())
+            .segments()
+            .map(|s| s.horizontal_ray_intersection_type(point))
+            .sum()
+    }
+}
+
+// Splits the rings wherever they cross and keeps the candidate pieces that have nothing
+// but uncovered space on their right, i.e. the boundary of the union of the rings.
+fn trim(rings: &[BandRing], tolerance: Scalar) -> Vec<Vec<Point>> {
+    let mut cuts: Vec<Vec<(usize, Scalar, Point)>> = rings
+        .iter()
+        .map(|ring| {
+            let m = ring.num_segments();
+            (0..m)
+                .filter(|&i| ring.candidate[i] != ring.candidate[(i + m - 1) % m])
+                .map(|i| (i, 0.0, ring.points[i]))
+                .collect()
+        })
+        .collect();
+
+    let chains: Vec<Chain> = rings.iter().map(|r| Chain::new(r.points.clone())).collect();
+    for intersection in chain_intersections(&chains) {
+        for (ring, segment) in [intersection.first, intersection.second] {
+            let points = &rings[ring].points;
+            let t = Segment::new(points[segment], points[segment + 1]).project(intersection.point);
+            cuts[ring].push((segment, t, intersection.point));
+        }
+    }
+
+    let mut pieces = Vec::new();
+    for (ring, mut cuts) in rings.iter().zip(cuts) {
+        if !ring.candidate.contains(&true) {
+            continue;
+        }
+        if cuts.is_empty() {
+            if is_outer_boundary(rings, &ring.points, tolerance) {
+                pieces.push(ring.points.clone());
+            }
+            continue;
+        }
+
+        cuts.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        let m = ring.num_segments();
+        for (k, &(start_segment, _, start)) in cuts.iter().enumerate() {
+            if !ring.candidate[start_segment] {
+                continue;
+            }
+
+            let (end_segment, _, end) = cuts[(k + 1) % cuts.len()];
+            let count = if k + 1 == cuts.len() {
+                end_segment + m - start_segment
+            } else {
+                end_segment - start_segment
+            };
+
+            let mut piece = vec![start];
+            piece.extend((1..=count).map(|i| ring.points[(start_segment + i) % m]));
+            piece.push(end);
+            piece.dedup_by(|a, b| a.distance(*b) <= tolerance);
+
+            // Chains folding back onto themselves produce the same boundary piece twice
+            let length: Scalar = piece.windows(2).map(|w| w[0].distance(w[1])).sum();
+            if length > tolerance
+                && !pieces.iter().any(|p| same_piece(p, &piece, tolerance))
+                && is_outer_boundary(rings, &piece, tolerance)
+            {
+                pieces.push(piece);
+            }
+        }
+    }
+    pieces
+}
+
+fn same_piece(first: &[Point], second: &[Point], tolerance: Scalar) -> bool {
+    first.len() == second.len()
+        && first
+            .iter()
+            .zip(second)
+            .all(|(p, q)| p.distance(*q) <= tolerance)
+}
+
+fn is_outer_boundary(rings: &[BandRing], piece: &[Point], tolerance: Scalar) -> bool {
+    let Some(longest) = piece
+        .windows(2)
+        .map(|w| Segment::new(w[0], w[1]))
+        .max_by(|s1, s2| s1.length().total_cmp(&s2.length()))
+    else {
+        return false;
+    };
+
+    // Covered on the left and uncovered on the right
+    let right = -longest.as_vector().normalize().perp() * (16.0 * tolerance);
+    let winding = |probe: Point| rings.iter().map(|r| r.winding_number(probe)).sum::<i32>();
+    winding(longest.point_at(0.5) + right) == 0 && winding(longest.point_at(0.5) - right) > 0
+}
+
+fn stitch(pieces: Vec<Vec<Point>>, tolerance: Scalar) -> Vec<Chain> {
+    let mut used = vec![false; pieces.len()];
+    let mut rings = Vec::new();
+    for first in 0..pieces.len() {
+        if used[first] {
+            continue;
+        }
+        used[first] = true;
+
+        let mut ring = pieces[first].clone();
+        loop {
+            let end = ring[ring.len() - 1];
+            if end.distance(ring[0]) <= tolerance {
+                break;
+            }
+
+            let next = (0..pieces.len()).filter(|&i| !used[i]).min_by(|&i, &j| {
+                end.distance(pieces[i][0])
+                    .total_cmp(&end.distance(pieces[j][0]))
+            });
+            match next {
+                Some(i) if end.distance(pieces[i][0]) <= tolerance => {
+                    used[i] = true;
+                    ring.extend(&pieces[i][1..]);
+                }
+                _ => break,
+            }
+        }
+
+        if ring[ring.len() - 1].distance(ring[0]) <= tolerance {
+            ring.pop();
+        }
+        let mut ring = remove_collinear(&ring, tolerance);
+        while ring.len() >= 3 {
+            let n = ring.len();
+            if is_collinear(ring[n - 1], ring[0], ring[1], tolerance) {
+                ring.remove(0);
+            } else if is_collinear(ring[n - 2], ring[n - 1], ring[0], tolerance) {
+                ring.pop();
+            } else {
+                break;
+            }
+        }
+        // Whatever is left of a ring that folded back onto itself encloses nothing
+        if ring.len() >= 3 {
+            ring.push(ring[0]);
+            rings.push(Chain::new(ring));
+        }
+    }
+    rings
+}
+
+fn join_pieces(pieces: Vec<Vec<Point>>, tolerance: Scalar) -> Vec<Chain> {
+    let mut chains: Vec<Vec<Point>> = Vec::new();
+    for piece in pieces {
+        match chains.last_mut() {
+            Some(chain) if chain[chain.len() - 1].distance(piece[0]) <= tolerance => {
+                chain.extend(&piece[1..]);
+            }
+            _ => chains.push(piece),
+        }
+    }
+    chains
+        .into_iter()
+        .map(|chain| Chain::new(remove_collinear(&chain, tolerance)))
+        .collect()
+}
+
+// Drops the vertices that continue straight on or turn back on themselves.
+fn remove_collinear(points: &[Point], tolerance: Scalar) -> Vec<Point> {
+    let mut kept: Vec<Point> = Vec::with_capacity(points.len());
+    for &point in points {
+        while kept.len() >= 2
+            && is_collinear(kept[kept.len() - 2], kept[kept.len() - 1], point, tolerance)
+        {
+            kept.pop();
+        }
+        if kept
+            .last()
+            .is_none_or(|last| last.distance(point) > tolerance)
+        {
+            kept.push(point);
+        }
+    }
+    kept
+}
+
+fn is_collinear(a: Point, b: Point, c: Point, tolerance: Scalar) -> bool {
+    (b - a).perp_dot(c - b).abs() <= tolerance * (a.distance(b) + b.distance(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::approx::assert_approx_eq;
+
+    use super::*;
+
+    fn l_shape() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+        ])
+    }
+
+    fn square() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+            Point::new(0.0, 0.0),
+        ])
+    }
+
+    fn assert_same_ring(actual: &Chain, expected: &[Point]) {
+        assert!(actual.is_closed());
+        assert_eq!(actual.num_vertices(), expected.len() + 1);
+        for vertex in expected {
+            assert!(
+                actual
+                    .vertices()
+                    .any(|v| v.distance(*vertex) < 64.0 * Scalar::EPSILON),
+                "{vertex:?} missing from {actual:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn offset_segment() {
+        let chain = Chain::from(Segment::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0)));
+
+        let left = chain.offset(1.0, JoinStyle::Round);
+        assert_eq!(left.len(), 1);
+        assert_approx_eq!(
+            left[0],
+            Chain::new(vec![Point::new(0.0, 1.0), Point::new(10.0, 1.0)])
+        );
+
+        let right = chain.offset(-1.0, JoinStyle::Round);
+        assert_eq!(right.len(), 1);
+        assert_approx_eq!(
+            right[0],
+            Chain::new(vec![Point::new(0.0, -1.0), Point::new(10.0, -1.0)])
+        );
+    }
+
+    #[test]
+    fn offset_miter() {
+        let offset = l_shape().offset(-1.0, JoinStyle::Miter(2.0));
+        assert_eq!(offset.len(), 1);
+        assert_approx_eq!(
+            offset[0],
+            Chain::new(vec![
+                Point::new(0.0, -1.0),
+                Point::new(11.0, -1.0),
+                Point::new(11.0, 10.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn offset_miter_limit() {
+        let offset = l_shape().offset(-1.0, JoinStyle::Miter(1.2));
+        assert_eq!(offset.len(), 1);
+        assert_approx_eq!(
+            offset[0],
+            Chain::new(vec![
+                Point::new(0.0, -1.0),
+                Point::new(10.0, -1.0),
+                Point::new(11.0, 0.0),
+                Point::new(11.0, 10.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn offset_bevel() {
+        let offset = l_shape().offset(-1.0, JoinStyle::Bevel);
+        assert_eq!(offset.len(), 1);
+        assert_approx_eq!(
+            offset[0],
+            Chain::new(vec![
+                Point::new(0.0, -1.0),
+                Point::new(10.0, -1.0),
+                Point::new(11.0, 0.0),
+                Point::new(11.0, 10.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn offset_round() {
+        let offset = l_shape().offset(-1.0, JoinStyle::Round);
+        assert_eq!(offset.len(), 1);
+        assert_eq!(offset[0].num_vertices(), ARC_SEGMENTS_PER_QUARTER + 3);
+
+        let corner = Point::new(10.0, 0.0);
+        for vertex in offset[0]
+            .vertices()
+            .skip(1)
+            .take(ARC_SEGMENTS_PER_QUARTER + 1)
+        {
+            assert_approx_eq!(vertex.distance(corner), 1.0, 4.0 * Scalar::EPSILON);
+        }
+    }
+
+    #[test]
+    fn offset_inner_corner() {
+        let offset = l_shape().offset(1.0, JoinStyle::Round);
+        assert_eq!(offset.len(), 1);
+        assert_approx_eq!(
+            offset[0],
+            Chain::new(vec![
+                Point::new(0.0, 1.0),
+                Point::new(9.0, 1.0),
+                Point::new(9.0, 10.0),
+            ]),
+            16.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn offset_closed() {
+        let outward = square().offset(-1.0, JoinStyle::Miter(2.0));
+        assert_eq!(outward.len(), 1);
+        assert_same_ring(
+            &outward[0],
+            &[
+                Point::new(-1.0, -1.0),
+                Point::new(5.0, -1.0),
+                Point::new(5.0, 5.0),
+                Point::new(-1.0, 5.0),
+            ],
+        );
+        assert_approx_eq!(outward[0].signed_area(), 36.0, 64.0 * Scalar::EPSILON);
+
+        let inward = square().offset(1.0, JoinStyle::Miter(2.0));
+        assert_eq!(inward.len(), 1);
+        assert_same_ring(
+            &inward[0],
+            &[
+                Point::new(1.0, 1.0),
+                Point::new(3.0, 1.0),
+                Point::new(3.0, 3.0),
+                Point::new(1.0, 3.0),
+            ],
+        );
+        assert_approx_eq!(inward[0].signed_area(), 4.0, 16.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn offset_collapsed() {
+        assert_eq!(square().offset(3.0, JoinStyle::Round), vec![]);
+    }
+
+    #[test]
+    fn buffer_butt() {
+        let chain = Chain::from(Segment::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0)));
+        let buffer = chain.buffer(1.0, JoinStyle::Round, CapStyle::Butt);
+
+        assert_eq!(buffer.len(), 1);
+        assert_same_ring(
+            &buffer[0],
+            &[
+                Point::new(0.0, -1.0),
+                Point::new(10.0, -1.0),
+                Point::new(10.0, 1.0),
+                Point::new(0.0, 1.0),
+            ],
+        );
+        assert_approx_eq!(buffer[0].signed_area(), 20.0, 64.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn buffer_square() {
+        let chain = Chain::from(Segment::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0)));
+        let buffer = chain.buffer(1.0, JoinStyle::Round, CapStyle::Square);
+
+        assert_eq!(buffer.len(), 1);
+        assert_same_ring(
+            &buffer[0],
+            &[
+                Point::new(-1.0, -1.0),
+                Point::new(11.0, -1.0),
+                Point::new(11.0, 1.0),
+                Point::new(-1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn buffer_round() {
+        let chain = l_shape();
+        let buffer = chain.buffer(1.0, JoinStyle::Round, CapStyle::Round);
+
+        assert_eq!(buffer.len(), 1);
+        assert!(buffer[0].is_closed());
+        assert!(buffer[0].is_simple());
+        for vertex in buffer[0].vertices() {
+            assert_approx_eq!(chain.distance_to_point(vertex), 1.0, 4.0 * Scalar::EPSILON);
+        }
+
+        assert_approx_eq!(buffer[0].signed_area(), 39.0 + 1.25 * PI, 0.1);
+    }
+
+    #[test]
+    fn buffer_closed_has_hole() {
+        let buffer = square().buffer(1.0, JoinStyle::Miter(2.0), CapStyle::Butt);
+        assert_eq!(buffer.len(), 2);
+
+        let mut areas: Vec<Scalar> = buffer.iter().map(Chain::signed_area).collect();
+        areas.sort_by(Scalar::total_cmp);
+        assert_approx_eq!(areas[0], -4.0, 64.0 * Scalar::EPSILON);
+        assert_approx_eq!(areas[1], 36.0, 64.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn buffer_overlapping_ends() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+            Point::new(0.0, 1.0),
+        ]);
+        let buffer = chain.buffer(1.0, JoinStyle::Miter(2.0), CapStyle::Square);
+        assert_eq!(buffer.len(), 2);
+
+        let mut areas: Vec<Scalar> = buffer.iter().map(Chain::signed_area).collect();
+        areas.sort_by(Scalar::total_cmp);
+        assert_approx_eq!(areas[0], -64.0, 256.0 * Scalar::EPSILON);
+        assert_approx_eq!(areas[1], 144.0, 256.0 * Scalar::EPSILON);
+        assert!(buffer.iter().all(Chain::is_simple));
+    }
+
+    #[test]
+    fn buffer_point() {
+        let chain = Chain::from(Point::new(1.0, 1.0));
+
+        let round = chain.buffer(2.0, JoinStyle::Round, CapStyle::Round);
+        assert_eq!(round.len(), 1);
+        assert_eq!(round[0].num_vertices(), 4 * ARC_SEGMENTS_PER_QUARTER + 1);
+
+        let square = chain.buffer(2.0, JoinStyle::Round, CapStyle::Square);
+        assert_eq!(square.len(), 1);
+        assert_approx_eq!(square[0].signed_area(), 16.0);
+
+        assert_eq!(chain.buffer(2.0, JoinStyle::Round, CapStyle::Butt), vec![]);
+    }
+
+    #[test]
+    fn buffer_non_positive() {
+        assert_eq!(
+            l_shape().buffer(0.0, JoinStyle::Round, CapStyle::Round),
+            vec![]
+        );
+        assert_eq!(
+            l_shape().buffer(-1.0, JoinStyle::Round, CapStyle::Round),
+            vec![]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::geometry::{shape::Point, Scalar};
+
+use super::Chain;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainProjection {
+    pub segment_index: usize,
+    pub t: Scalar,
+    pub distance_along: Scalar,
+    pub point: Point,
+    pub distance: Scalar,
+}
+
+impl Chain {
+    pub fn project(&self, point: Point) -> Option<ChainProjection> {
+        let mut distance_along = 0.0;
+        let mut closest: Option<ChainProjection> = None;
+        for (segment_index, segment) in self.segments().enumerate() {
+            let t = segment.project(point);
+            let closest_point = segment.point_at(t);
+            let distance = point.distance(closest_point);
+            let length = segment.length();
+            if closest.as_ref().is_none_or(|c| distance < c.distance) {
+                closest = Some(ChainProjection {
+                    segment_index,
+                    t,
+                    distance_along: distance_along + t * length,
+                    point: closest_point,
+                    distance,
+                });
+            }
+            distance_along += length;
+        }
+        closest
+    }
+
+    pub fn substring(&self, start: Scalar, end: Scalar) -> Chain {
+        if start > end {
+            let mut reversed = self.substring(end, start);
+            reversed.vertices.reverse();
+            return reversed;
+        }
+
+        let cumulative = self.cumulative_lengths();
+        let total = cumulative.last().copied().unwrap_or(0.0);
+        let (start, end) = (start.clamp(0.0, total), end.clamp(0.0, total));
+        let Some((start_point, _)) = self.point_at_cumulative_distance(&cumulative, start) else {
+            return self.vertices().take(1).collect();
+        };
+        let Some((end_point, _)) = self.point_at_cumulative_distance(&cumulative, end) else {
+            return self.vertices().take(1).collect();
+        };
+
+        let mut vertices = vec![start_point];
+        vertices.extend(
+            self.vertices()
+                .zip(cumulative)
+                .skip_while(|&(_, distance)| distance <= start)
+                .take_while(|&(_, distance)| distance < end)
+                .map(|(vertex, _)| vertex),
+        );
+        vertices.push(end_point);
+        Chain::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::approx::assert_approx_eq;
+
+    use super::*;
+
+    fn l_shape() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn project() {
+        let projection = l_shape().project(Point::new(4.0, 3.0)).unwrap();
+
+        assert_eq!(projection.segment_index, 1);
+        assert_approx_eq!(projection.t, 0.75);
+        assert_approx_eq!(projection.distance_along, 6.0);
+        assert_approx_eq!(projection.point, Point::new(3.0, 3.0));
+        assert_approx_eq!(projection.distance, 1.0);
+    }
+
+    #[test]
+    fn project_before_start() {
+        let projection = l_shape().project(Point::new(-2.0, 1.0)).unwrap();
+
+        assert_eq!(projection.segment_index, 0);
+        assert_approx_eq!(projection.t, 0.0);
+        assert_approx_eq!(projection.distance_along, 0.0);
+        assert_approx_eq!(projection.point, Point::new(0.0, 0.0));
+        assert_approx_eq!(projection.distance, Scalar::sqrt(5.0));
+    }
+
+    #[test]
+    fn project_agrees_with_distance() {
+        let chain = l_shape();
+        let point = Point::new(1.0, 2.0);
+
+        assert_approx_eq!(
+            chain.project(point).unwrap().distance,
+            chain.distance_to_point(point)
+        );
+    }
+
+    #[test]
+    fn project_without_segments() {
+        assert_eq!(
+            Chain::from(Point::new(1.0, 1.0)).project(Point::ORIGIN),
+            None
+        );
+    }
+
+    #[test]
+    fn substring() {
+        assert_approx_eq!(
+            l_shape().substring(1.0, 5.0),
+            Chain::new(vec![
+                Point::new(1.0, 0.0),
+                Point::new(3.0, 0.0),
+                Point::new(3.0, 2.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn substring_within_segment() {
+        assert_approx_eq!(
+            l_shape().substring(1.0, 2.0),
+            Chain::new(vec![Point::new(1.0, 0.0), Point::new(2.0, 0.0)])
+        );
+    }
+
+    #[test]
+    fn substring_at_vertices() {
+        assert_approx_eq!(
+            l_shape().substring(3.0, 7.0),
+            Chain::new(vec![Point::new(3.0, 0.0), Point::new(3.0, 4.0)])
+        );
+        assert_approx_eq!(l_shape().substring(-1.0, 10.0), l_shape());
+    }
+
+    #[test]
+    fn substring_reversed() {
+        assert_approx_eq!(
+            l_shape().substring(5.0, 1.0),
+            Chain::new(vec![
+                Point::new(3.0, 2.0),
+                Point::new(3.0, 0.0),
+                Point::new(1.0, 0.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn substring_round_trip() {
+        let chain = l_shape();
+        let start = chain.project(Point::new(2.0, -1.0)).unwrap();
+        let end = chain.project(Point::new(4.0, 1.0)).unwrap();
+
+        assert_approx_eq!(
+            chain
+                .substring(start.distance_along, end.distance_along)
+                .length(),
+            2.0
+        );
+    }
+}
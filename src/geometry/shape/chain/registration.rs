@@ -0,0 +1,220 @@
+use crate::geometry::{shape::Point, transform::Similarity, Scalar};
+
+use super::Chain;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrationModel {
+    Rigid,
+    Similarity,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistrationOptions {
+    pub model: RegistrationModel,
+    pub max_iterations: usize,
+    // Points farther than this from the target take no part in a step
+    pub max_correspondence_distance: Scalar,
+    // Converged once a step improves the error by no more than this
+    pub tolerance: Scalar,
+    pub initial: Similarity,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Registration {
+    pub transform: Similarity,
+    // Share of the points within the maximum correspondence distance of the target
+    pub fitness: Scalar,
+    // Root mean square distance to the target over those points
+    pub inlier_rmse: Scalar,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+impl Chain {
+    // Iterative closest point: finds the transform that lays `points` onto the chain by
+    // repeatedly pairing each moved point with its closest point on the chain and fitting
+    // to those pairs. Only finds the nearest fit, so `initial` has to be roughly right.
+    pub fn register(
+        &self,
+        points: &[Point],
+        options: &RegistrationOptions,
+    ) -> Option<Registration> {
+        let fit = match options.model {
+            RegistrationModel::Rigid => Similarity::fit_rigid,
+            RegistrationModel::Similarity => Similarity::fit,
+        };
+
+        let mut transform = options.initial.clone();
+        let mut error = Scalar::INFINITY;
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < options.max_iterations && !converged {
+            let pairs = self.correspondences(points, &transform, options);
+            // Fitting the original points gives the whole transform rather than a step
+            let Some(step) = fit(&pairs) else {
+                break;
+            };
+            iterations += 1;
+            converged = (error - step.residual).abs() <= options.tolerance;
+            // A growing error means the pairing jumped to another part of the chain, so stop
+            // and keep the best pose so far
+            if step.residual > error {
+                break;
+            }
+            transform = step.similarity;
+            error = step.residual;
+        }
+
+        let pairs = self.correspondences(points, &transform, options);
+        if pairs.is_empty() {
+            return None;
+        }
+        let squared_error: Scalar = pairs
+            .iter()
+            .map(|&(point, target)| (&transform * point).distance(target).powi(2))
+            .sum();
+        Some(Registration {
+            fitness: pairs.len() as Scalar / points.len() as Scalar,
+            inlier_rmse: (squared_error / pairs.len() as Scalar).sqrt(),
+            transform,
+            iterations,
+            converged,
+        })
+    }
+
+    // Each point paired with the closest point on the chain to where `transform` moves it
+    fn correspondences(
+        &self,
+        points: &[Point],
+        transform: &Similarity,
+        options: &RegistrationOptions,
+    ) -> Vec<(Point, Point)> {
+        points
+            .iter()
+            .filter_map(|&point| {
+                let projection = self.project(transform * point)?;
+                (projection.distance <= options.max_correspondence_distance)
+                    .then_some((point, projection.point))
+            })
+            .collect()
+    }
+}
+
+impl Default for RegistrationOptions {
+    fn default() -> Self {
+        Self {
+            model: RegistrationModel::Rigid,
+            max_iterations: 50,
+            max_correspondence_distance: Scalar::INFINITY,
+            tolerance: 1e-6,
+            initial: Similarity::IDENT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{geometry::vector, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    // Corner of a room with a doorway step, so no slide along it fits as well
+    fn map() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 6.0),
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.0),
+            Point::new(5.0, 1.0),
+            Point::new(7.0, 1.0),
+            Point::new(7.0, 0.0),
+            Point::new(10.0, 0.0),
+        ])
+    }
+
+    // The map as seen from a pose that is `offset` away from the one assumed
+    fn scan(offset: &Similarity) -> Vec<Point> {
+        let inverse = offset.inverse();
+        map()
+            .resample(80)
+            .vertices()
+            .map(|p| &inverse * p)
+            .collect()
+    }
+
+    #[test]
+    fn rigid() {
+        let offset = Similarity::from_parts(1.0, 0.05, vector!(0.3, -0.2));
+        let registration = map()
+            .register(&scan(&offset), &RegistrationOptions::default())
+            .unwrap();
+
+        assert!(registration.converged);
+        assert_approx_eq!(registration.transform, offset, 1e-5);
+        assert_approx_eq!(registration.fitness, 1.0);
+        assert!(registration.inlier_rmse < 1e-5);
+    }
+
+    #[test]
+    fn similarity() {
+        let offset = Similarity::from_parts(1.05, -0.04, vector!(-0.2, 0.1));
+        let options = RegistrationOptions {
+            model: RegistrationModel::Similarity,
+            max_iterations: 200,
+            ..RegistrationOptions::default()
+        };
+        let registration = map().register(&scan(&offset), &options).unwrap();
+
+        assert!(registration.converged);
+        assert_approx_eq!(registration.transform, offset, 1e-4);
+    }
+
+    #[test]
+    fn outliers() {
+        let offset = Similarity::from_parts(1.0, 0.05, vector!(0.3, -0.2));
+        let mut points = scan(&offset);
+        // Someone standing in the room
+        points.extend((0..20).map(|i| Point::new(3.0 + 0.01 * i as Scalar, 3.0)));
+        let options = RegistrationOptions {
+            max_correspondence_distance: 1.0,
+            ..RegistrationOptions::default()
+        };
+
+        let registration = map().register(&points, &options).unwrap();
+        assert_approx_eq!(registration.transform, offset, 1e-5);
+        assert_approx_eq!(registration.fitness, 80.0 / 100.0);
+
+        let far = [Point::new(50.0, 50.0)];
+        assert_eq!(map().register(&far, &options), None);
+    }
+
+    #[test]
+    fn keeps_best_pose_when_error_grows() {
+        let wall = Chain::new(vec![Point::ORIGIN, Point::new(10.0, 0.0)]);
+        // The last point only comes within reach after the first step and pulls the fit away
+        let mut points: Vec<Point> = (1..=5).map(|i| Point::new(i as Scalar, 1.0)).collect();
+        points.push(Point::new(5.0, 3.5));
+        let options = RegistrationOptions {
+            max_correspondence_distance: 3.0,
+            ..RegistrationOptions::default()
+        };
+
+        let registration = wall.register(&points, &options).unwrap();
+        assert!(!registration.converged);
+        assert_eq!(registration.iterations, 2);
+        assert_approx_eq!(
+            registration.transform,
+            Similarity::from_parts(1.0, 0.0, vector!(0.0, -1.0))
+        );
+    }
+
+    #[bench]
+    fn register_bench(b: &mut Bencher) {
+        let map = map();
+        let points = scan(&Similarity::from_parts(1.0, 0.05, vector!(0.3, -0.2)));
+        let options = RegistrationOptions::default();
+
+        b.iter(|| black_box(map.register(&points, &options)));
+    }
+}
@@ -0,0 +1,495 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use crate::geometry::{
+    shape::{Point, Segment, Triangle},
+    Scalar,
+};
+
+use super::Chain;
+
+impl Chain {
+    pub fn simplify(&self, tolerance: Scalar) -> Chain {
+        if self.num_vertices() < 3 {
+            return self.clone();
+        }
+
+        let keep = self.douglas_peucker(tolerance);
+        self.kept_vertices(&keep)
+    }
+
+    pub fn simplify_preserve_topology(&self, tolerance: Scalar) -> Chain {
+        if self.num_vertices() < 3 {
+            return self.clone();
+        }
+
+        let mut keep = self.douglas_peucker(tolerance);
+        let kept: Vec<usize> = (0..self.num_vertices()).filter(|&i| keep[i]).collect();
+        let mut grid = SpanGrid::new(self);
+        for span in kept.windows(2) {
+            grid.insert(self, (span[0], span[1]));
+        }
+
+        // Only spans that skip vertices can introduce an intersection
+        let mut pending: Vec<(usize, usize)> = kept
+            .windows(2)
+            .map(|span| (span[0], span[1]))
+            .filter(|(start, end)| end - start > 1)
+            .rev()
+            .collect();
+        while let Some(span) = pending.pop() {
+            if !grid.contains(span) || grid.crossing(self, span).is_empty() {
+                continue;
+            }
+            let Some((index, _)) = self.farthest_vertex(span.0, span.1) else {
+                continue;
+            };
+
+            keep[index] = true;
+            grid.remove(self, span);
+            for part in [(span.0, index), (index, span.1)] {
+                grid.insert(self, part);
+                // The detour through the kept vertex can cross spans that were fine before
+                for other in grid.crossing(self, part) {
+                    if other.1 - other.0 > 1 {
+                        pending.push(other);
+                    }
+                }
+                if part.1 - part.0 > 1 {
+                    pending.push(part);
+                }
+            }
+        }
+        self.kept_vertices(&keep)
+    }
+
+    pub fn simplify_visvalingam(&self, area_tolerance: Scalar) -> Chain {
+        let n = self.num_vertices();
+        if n < 3 {
+            return self.clone();
+        }
+
+        let mut prev: Vec<usize> = (0..n).map(|i| i.saturating_sub(1)).collect();
+        let mut next: Vec<usize> = (0..n).map(|i| (i + 1).min(n - 1)).collect();
+        let mut areas: Vec<Scalar> = (0..n)
+            .map(|i| {
+                if i == 0 || i == n - 1 {
+                    Scalar::INFINITY
+                } else {
+                    self.triangle_area(i - 1, i, i + 1)
+                }
+            })
+            .collect();
+        let mut heap: BinaryHeap<EffectiveArea> = (1..n - 1)
+            .map(|index| EffectiveArea {
+                area: areas[index],
+                index,
+            })
+            .collect();
+
+        let mut keep = vec![true; n];
+        while let Some(EffectiveArea { area, index }) = heap.pop() {
+            if !keep[index] || area != areas[index] {
+                continue;
+            }
+            if area >= area_tolerance {
+                break;
+            }
+
+            keep[index] = false;
+            let (p, q) = (prev[index], next[index]);
+            next[p] = q;
+            prev[q] = p;
+
+            for neighbor in [p, q] {
+                if neighbor == 0 || neighbor == n - 1 {
+                    continue;
+                }
+                // Visvalingam's rule: a vertex never becomes less significant than one
+                // that was removed before it.
+                areas[neighbor] = self
+                    .triangle_area(prev[neighbor], neighbor, next[neighbor])
+                    .max(area);
+                heap.push(EffectiveArea {
+                    area: areas[neighbor],
+                    index: neighbor,
+                });
+            }
+        }
+        self.kept_vertices(&keep)
+    }
+
+    fn douglas_peucker(&self, tolerance: Scalar) -> Vec<bool> {
+        let last = self.num_vertices() - 1;
+        let mut keep = vec![false; self.num_vertices()];
+        keep[0] = true;
+        keep[last] = true;
+
+        let mut stack = vec![(0, last)];
+        while let Some((start, end)) = stack.pop() {
+            let Some((index, distance)) = self.farthest_vertex(start, end) else {
+                continue;
+            };
+            if distance > tolerance {
+                keep[index] = true;
+                stack.push((start, index));
+                stack.push((index, end));
+            }
+        }
+        keep
+    }
+
+    fn farthest_vertex(&self, start: usize, end: usize) -> Option<(usize, Scalar)> {
+        let segment = Segment::new(self.vertices[start], self.vertices[end]);
+        (start + 1..end)
+            .map(|i| (i, segment.distance_to_point(self.vertices[i])))
+            .max_by(|(_, d1), (_, d2)| d1.total_cmp(d2))
+    }
+
+    fn triangle_area(&self, a: usize, b: usize, c: usize) -> Scalar {
+        Triangle::new(self.vertices[a], self.vertices[b], self.vertices[c]).area()
+    }
+
+    fn kept_vertices(&self, keep: &[bool]) -> Chain {
+        self.vertices()
+            .zip(keep)
+            .filter(|(_, &keep)| keep)
+            .map(|(vertex, _)| vertex)
+            .collect()
+    }
+}
+
+struct EffectiveArea {
+    area: Scalar,
+    index: usize,
+}
+
+impl PartialEq for EffectiveArea {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EffectiveArea {}
+
+impl PartialOrd for EffectiveArea {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EffectiveArea {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, so that the max-heap pops the smallest area first
+        other
+            .area
+            .total_cmp(&self.area)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+// Uniform grid over the chain holding the spans between kept vertices, each listed in every
+// cell its bounding box touches, so a span is only tested against spans close to it
+struct SpanGrid {
+    min: Point,
+    cell_size: Scalar,
+    closed: bool,
+    last: usize,
+    spans: HashSet<(usize, usize)>,
+    cells: HashMap<(i64, i64), Vec<(usize, usize)>>,
+}
+
+impl SpanGrid {
+    fn new(chain: &Chain) -> Self {
+        let (min, max) = chain.vertices().fold(
+            (
+                Point::new(Scalar::INFINITY, Scalar::INFINITY),
+                Point::new(Scalar::NEG_INFINITY, Scalar::NEG_INFINITY),
+            ),
+            |(min, max), p| {
+                (
+                    Point::new(min.x().min(p.x()), min.y().min(p.y())),
+                    Point::new(max.x().max(p.x()), max.y().max(p.y())),
+                )
+            },
+        );
+        // About as many cells along each side as the square root of the vertex count
+        let extent = (max.x() - min.x()).max(max.y() - min.y());
+        let cell_size = extent / (chain.num_vertices() as Scalar).sqrt();
+        let last = chain.num_vertices() - 1;
+
+        Self {
+            min,
+            cell_size: if cell_size > 0.0 { cell_size } else { 1.0 },
+            closed: chain.vertices[0] == chain.vertices[last],
+            last,
+            spans: HashSet::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn contains(&self, span: (usize, usize)) -> bool {
+        self.spans.contains(&span)
+    }
+
+    fn insert(&mut self, chain: &Chain, span: (usize, usize)) {
+        self.spans.insert(span);
+        for cell in self.cells_of(chain, span) {
+            self.cells.entry(cell).or_default().push(span);
+        }
+    }
+
+    fn remove(&mut self, chain: &Chain, span: (usize, usize)) {
+        self.spans.remove(&span);
+        for cell in self.cells_of(chain, span) {
+            if let Some(spans) = self.cells.get_mut(&cell) {
+                spans.retain(|&other| other != span);
+            }
+        }
+    }
+
+    // The spans that cross `span` without merely sharing an end point with it
+    fn crossing(&self, chain: &Chain, span: (usize, usize)) -> Vec<(usize, usize)> {
+        let segment = Segment::new(chain.vertices[span.0], chain.vertices[span.1]);
+        let mut candidates: Vec<(usize, usize)> = self
+            .cells_of(chain, span)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(|&other| other != span && !self.adjacent(span, other))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates.retain(|other| {
+            segment.intersects(&Segment::new(
+                chain.vertices[other.0],
+                chain.vertices[other.1],
+            ))
+        });
+        candidates
+    }
+
+    fn adjacent(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        a.1 == b.0
+            || b.1 == a.0
+            || (self.closed && ((a.0 == 0 && b.1 == self.last) || (b.0 == 0 && a.1 == self.last)))
+    }
+
+    fn cells_of(&self, chain: &Chain, span: (usize, usize)) -> impl Iterator<Item = (i64, i64)> {
+        let (start, end) = (chain.vertices[span.0], chain.vertices[span.1]);
+        let cell = |value: Scalar, min: Scalar| ((value - min) / self.cell_size).floor() as i64;
+        let xs =
+            cell(start.x().min(end.x()), self.min.x())..=cell(start.x().max(end.x()), self.min.x());
+        let ys =
+            cell(start.y().min(end.y()), self.min.y())..=cell(start.y().max(end.y()), self.min.y());
+        xs.flat_map(move |x| ys.clone().map(move |y| (x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::utils::approx::assert_approx_eq;
+
+    use super::*;
+
+    fn zigzag() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.1),
+            Point::new(2.0, -0.1),
+            Point::new(3.0, 5.0),
+            Point::new(4.0, 6.0),
+            Point::new(5.0, 7.0),
+            Point::new(6.0, 8.1),
+            Point::new(7.0, 9.0),
+        ])
+    }
+
+    #[test]
+    fn simplify() {
+        assert_approx_eq!(
+            zigzag().simplify(0.5),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, -0.1),
+                Point::new(3.0, 5.0),
+                Point::new(7.0, 9.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn simplify_zero_tolerance() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 1.0),
+        ]);
+        assert_approx_eq!(chain.simplify(0.0), chain);
+    }
+
+    #[test]
+    fn simplify_collinear() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+            Point::new(3.0, 3.0),
+        ]);
+        assert_approx_eq!(
+            chain.simplify(Scalar::EPSILON),
+            Chain::new(vec![Point::new(0.0, 0.0), Point::new(3.0, 3.0)])
+        );
+    }
+
+    #[test]
+    fn simplify_closed() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert_approx_eq!(
+            chain.simplify(0.1),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, 0.0),
+                Point::new(2.0, 2.0),
+                Point::new(0.0, 2.0),
+                Point::new(0.0, 0.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn simplify_short() {
+        let chain = Chain::from(Segment::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)));
+        assert_approx_eq!(chain.simplify(10.0), chain);
+        assert_approx_eq!(chain.simplify_visvalingam(10.0), chain);
+        assert_approx_eq!(chain.simplify_preserve_topology(10.0), chain);
+    }
+
+    #[test]
+    fn simplify_preserve_topology() {
+        // The end of the chain pokes into a bump that plain simplification flattens
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.8),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, -3.0),
+            Point::new(7.5, -3.1),
+            Point::new(5.0, -3.0),
+            Point::new(5.0, 0.5),
+        ]);
+
+        let simplified = chain.simplify(1.0);
+        assert!(has_self_intersection(&simplified));
+
+        let preserved = chain.simplify_preserve_topology(1.0);
+        assert!(!has_self_intersection(&preserved));
+        assert_approx_eq!(
+            preserved,
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(5.0, 0.8),
+                Point::new(10.0, 0.0),
+                Point::new(10.0, -3.0),
+                Point::new(5.0, -3.0),
+                Point::new(5.0, 0.5),
+            ])
+        );
+    }
+
+    #[test]
+    fn simplify_preserve_topology_spiral() {
+        // Tight turns of a long noisy spiral, which plain simplification cuts across
+        let chain: Chain = (0..20_000)
+            .map(|i| {
+                let angle = 1.0 + i as Scalar * 0.01;
+                let radius = 0.05 * angle * (1.0 + (i as Scalar * 12.9898).sin() * 0.002);
+                Point::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect();
+
+        assert!(has_self_intersection(&chain.simplify(0.5)));
+        let preserved = chain.simplify_preserve_topology(0.5);
+        assert!(!has_self_intersection(&preserved));
+        assert!(preserved.num_vertices() < chain.num_vertices() / 10);
+    }
+
+    #[test]
+    fn simplify_visvalingam() {
+        assert_approx_eq!(
+            zigzag().simplify_visvalingam(0.5),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, -0.1),
+                Point::new(3.0, 5.0),
+                Point::new(7.0, 9.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn simplify_visvalingam_keeps_large_areas() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 0.0),
+        ]);
+        assert_approx_eq!(chain.simplify_visvalingam(1.0), chain);
+        assert_approx_eq!(
+            chain.simplify_visvalingam(1.0 + Scalar::EPSILON),
+            Chain::new(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0)])
+        );
+    }
+
+    fn has_self_intersection(chain: &Chain) -> bool {
+        let segments: Vec<Segment> = chain.segments().collect();
+        for (i, s1) in segments.iter().enumerate() {
+            for s2 in segments.iter().skip(i + 2) {
+                if s1.intersects(s2) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn noisy_line(n: usize) -> Chain {
+        (0..n)
+            .map(|i| {
+                let x = i as Scalar;
+                Point::new(x, (x * 12.9898).sin() * 0.01)
+            })
+            .collect()
+    }
+
+    #[bench]
+    fn simplify_1000_vertices_bench(b: &mut Bencher) {
+        let chain = noisy_line(1000);
+
+        b.iter(|| black_box(chain.simplify(0.1)));
+    }
+
+    #[bench]
+    fn simplify_preserve_topology_10000_vertices_bench(b: &mut Bencher) {
+        let chain = noisy_line(10_000);
+
+        b.iter(|| black_box(chain.simplify_preserve_topology(0.1)));
+    }
+
+    #[bench]
+    fn simplify_visvalingam_1000_vertices_bench(b: &mut Bencher) {
+        let chain = noisy_line(1000);
+
+        b.iter(|| black_box(chain.simplify_visvalingam(0.1)));
+    }
+}
@@ -1,11 +1,14 @@
 use std::ops::{Mul, MulAssign};
 
 use crate::{
-    geometry::{transform::Similarity, Scalar},
+    geometry::{
+        transform::{Affine, Similarity},
+        Scalar,
+    },
     utils::approx::ApproxEq,
 };
 
-use super::Point;
+use super::{Ellipse, Point, Ray, Rect, SignedDistance};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Circle {
@@ -26,12 +29,38 @@ impl Circle {
         self.center.distance(point) - self.radius
     }
 
+    pub fn bounding_box(&self) -> Rect {
+        Rect::from_center(self.center, self.radius, self.radius)
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        let offset = ray.origin - self.center;
+        let a = ray.direction.norm_sq();
+        let b = offset.dot(ray.direction);
+        let c = offset.norm_sq() - self.radius * self.radius;
+        let discriminant = b * b - a * c;
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        [(-b - root) / a, (-b + root) / a]
+            .into_iter()
+            .find(|&t| t >= 0.0)
+    }
+
     pub fn transform(&mut self, t: &Similarity) {
         self.center *= t;
         self.radius *= t.scale();
     }
 }
 
+impl SignedDistance for Circle {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Circle::signed_distance(self, point)
+    }
+}
+
 impl ApproxEq for Circle {
     type Epsilon = <Scalar as ApproxEq>::Epsilon;
 
@@ -56,10 +85,19 @@ impl MulAssign<&Similarity> for Circle {
     }
 }
 
+// Circles are only closed under similarities, so a general affine map gives an ellipse
+impl Mul<Circle> for &Affine {
+    type Output = Ellipse;
+
+    fn mul(self, rhs: Circle) -> Self::Output {
+        self * Ellipse::from(rhs)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        geometry::{scalar, vector},
+        geometry::{scalar, vector, Vector},
         utils::approx::assert_approx_eq,
     };
 
@@ -86,6 +124,40 @@ mod tests {
         assert_approx_eq!(circle.signed_distance(Point::new(1.0, 1.0)), -1.0)
     }
 
+    #[test]
+    fn cast_ray() {
+        let circle = Circle::new(Point::new(1.0, 1.0), 1.0);
+
+        let ray = Ray::new(Point::new(-2.0, 1.0), vector!(2.0, 0.0));
+        assert_approx_eq!(circle.cast_ray(&ray).unwrap(), 1.0);
+
+        let inside = Ray::new(Point::new(1.0, 1.0), Vector::Y);
+        assert_approx_eq!(circle.cast_ray(&inside).unwrap(), 1.0);
+
+        let miss = Ray::new(Point::new(-2.0, 2.5), Vector::X);
+        assert_eq!(circle.cast_ray(&miss), None);
+        assert_eq!(circle.cast_ray(&Ray::new(ray.origin, -Vector::X)), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let circle = Circle::new(Point::new(1.0, 2.0), 1.5);
+        assert_approx_eq!(
+            circle.bounding_box(),
+            Rect::new(Point::new(-0.5, 0.5), Point::new(2.5, 3.5))
+        );
+    }
+
+    #[test]
+    fn affine_transform() {
+        let circle = Circle::new(Point::new(1.0, 2.0), 1.0);
+        let ellipse = &Affine::from_scale(vector!(1.0, 2.0)) * circle;
+
+        assert_approx_eq!(ellipse.center, Point::new(1.0, 4.0));
+        assert_approx_eq!(ellipse.signed_distance(Point::new(1.0, 7.0)), 1.0);
+        assert_approx_eq!(ellipse.signed_distance(Point::new(3.0, 4.0)), 1.0);
+    }
+
     #[test]
     fn transform() {
         let mut circle = Circle::new(Point::new(1.0, 2.0), 1.0);
@@ -0,0 +1,95 @@
+use crate::geometry::{shape::Rect, Scalar};
+
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(usize),
+    Branch(usize, usize),
+}
+
+// Bounding volume hierarchy over a fixed set of boxes, built top-down by splitting at the
+// median along the longer axis. Children are stored before their parents, so the root is
+// the last node.
+#[derive(Debug, Clone)]
+pub(super) struct Bvh {
+    nodes: Vec<Node>,
+    bounds: Vec<Rect>,
+}
+
+impl Bvh {
+    // Items without a box have no points, so they are left out
+    pub fn new(boxes: &[Option<Rect>]) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(2 * boxes.len()),
+            bounds: Vec::with_capacity(2 * boxes.len()),
+        };
+        let mut items: Vec<(usize, Rect)> = boxes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, rect)| Some((i, rect.clone()?)))
+            .collect();
+        if !items.is_empty() {
+            bvh.build(&mut items);
+        }
+        bvh
+    }
+
+    // Smallest value over all leaves. Subtrees are skipped when the lower bound of their box
+    // is no better than the best value so far, and the nearer child is always visited first.
+    pub fn min_by(
+        &self,
+        lower_bound: impl Fn(&Rect) -> Scalar,
+        mut value: impl FnMut(usize) -> Scalar,
+    ) -> Scalar {
+        let Some(root) = self.nodes.len().checked_sub(1) else {
+            return Scalar::INFINITY;
+        };
+
+        let mut best = Scalar::INFINITY;
+        let mut stack = vec![(root, lower_bound(&self.bounds[root]))];
+        while let Some((node, bound)) = stack.pop() {
+            if bound >= best {
+                continue;
+            }
+            match self.nodes[node] {
+                Node::Leaf(index) => best = best.min(value(index)),
+                Node::Branch(left, right) => {
+                    let left = (left, lower_bound(&self.bounds[left]));
+                    let right = (right, lower_bound(&self.bounds[right]));
+                    if left.1 <= right.1 {
+                        stack.extend([right, left]);
+                    } else {
+                        stack.extend([left, right]);
+                    }
+                }
+            }
+        }
+        best
+    }
+
+    fn build(&mut self, items: &mut [(usize, Rect)]) -> usize {
+        if let [(index, rect)] = items {
+            return self.push(Node::Leaf(*index), rect.clone());
+        }
+
+        let centers = Rect::from_points(items.iter().map(|(_, r)| r.center())).unwrap();
+        let axis: fn(&Rect) -> Scalar = if centers.width() >= centers.height() {
+            |r| r.center().x()
+        } else {
+            |r| r.center().y()
+        };
+        let middle = items.len() / 2;
+        items.select_nth_unstable_by(middle, |(_, r1), (_, r2)| axis(r1).total_cmp(&axis(r2)));
+
+        let (first, second) = items.split_at_mut(middle);
+        let left = self.build(first);
+        let right = self.build(second);
+        let bounds = self.bounds[left].union(&self.bounds[right]);
+        self.push(Node::Branch(left, right), bounds)
+    }
+
+    fn push(&mut self, node: Node, bounds: Rect) -> usize {
+        self.nodes.push(node);
+        self.bounds.push(bounds);
+        self.nodes.len() - 1
+    }
+}
@@ -0,0 +1,390 @@
+mod bvh;
+
+use std::ops::{Mul, MulAssign};
+
+use crate::geometry::{transform::Similarity, Scalar};
+
+use self::bvh::Bvh;
+
+use super::{Point, Ray, Rect, Shape, SignedDistance, RAY_POINT_TOLERANCE};
+
+// Shapes that move together. Each child has a pose relative to the compound, and the
+// compound as a whole has a pose in the world.
+#[derive(Debug, Clone)]
+pub struct Compound {
+    children: Vec<(Similarity, Shape)>,
+    pose: Similarity,
+    // The children with their own poses applied, so queries only need to undo `pose`
+    local_shapes: Vec<Shape>,
+    bvh: Bvh,
+}
+
+impl Compound {
+    pub fn new(children: Vec<(Similarity, Shape)>) -> Self {
+        let local_shapes: Vec<Shape> = children
+            .iter()
+            .map(|(pose, shape)| shape.clone() * pose)
+            .collect();
+        let boxes: Vec<Option<Rect>> = local_shapes.iter().map(|s| s.bounding_box()).collect();
+        Self {
+            children,
+            pose: Similarity::IDENT,
+            local_shapes,
+            bvh: Bvh::new(&boxes),
+        }
+    }
+
+    pub fn children(&self) -> &[(Similarity, Shape)] {
+        &self.children
+    }
+
+    pub const fn pose(&self) -> &Similarity {
+        &self.pose
+    }
+
+    pub fn set_pose(&mut self, pose: Similarity) {
+        self.pose = pose;
+    }
+
+    // Signed distance to the nearest child, or infinity without children
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        let local = &self.pose.inverse() * point;
+        let distance = self.bvh.min_by(
+            |rect| rect.signed_distance(local),
+            |i| self.local_shapes[i].distance_to_point(local),
+        );
+        distance * self.pose.scale()
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        // Parameters along the ray are the same in the local frame
+        let local = &self.pose.inverse() * ray.clone();
+        let t = self.bvh.min_by(
+            |rect| {
+                // Grown by the tolerance for hitting points, so point children are not skipped
+                let distance = local.origin.distance(rect.center()) + rect.half_extents().norm();
+                let rect = rect.expand(RAY_POINT_TOLERANCE * distance);
+                if rect.contains(local.origin) {
+                    0.0
+                } else {
+                    rect.cast_ray(&local).unwrap_or(Scalar::INFINITY)
+                }
+            },
+            |i| {
+                self.local_shapes[i]
+                    .cast_ray(&local)
+                    .unwrap_or(Scalar::INFINITY)
+            },
+        );
+        (t < Scalar::INFINITY).then_some(t)
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.local_shapes
+            .iter()
+            .filter_map(|s| (s.clone() * &self.pose).bounding_box())
+            .reduce(|r1, r2| r1.union(&r2))
+    }
+
+    // Only moves the root pose. The children keep their poses relative to it.
+    pub fn transform(&mut self, t: &Similarity) {
+        // Applies `t` after the current pose
+        self.pose = t.compose(&self.pose);
+    }
+}
+
+impl SignedDistance for Compound {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Compound::signed_distance(self, point)
+    }
+}
+
+impl Mul<Compound> for &Similarity {
+    type Output = Compound;
+
+    fn mul(self, mut rhs: Compound) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Compound {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl FromIterator<(Similarity, Shape)> for Compound {
+    fn from_iter<T: IntoIterator<Item = (Similarity, Shape)>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{
+            scalar::consts::FRAC_PI_2,
+            shape::{Chain, Circle, Line, Rounded, Segment},
+            vector, Vector,
+        },
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn wheel() -> Shape {
+        Shape::from(Rounded::new(Shape::from(Point::ORIGIN), 1.0))
+    }
+
+    // Two wheels of radius 1 at x = 0 and x = 4 with an axle between them
+    fn vehicle() -> Compound {
+        Compound::new(vec![
+            (Similarity::IDENT, wheel()),
+            (Similarity::from_parts(1.0, 0.0, vector!(4.0, 0.0)), wheel()),
+            (
+                Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0)),
+                Shape::from(Segment::new(Point::ORIGIN, Point::new(0.0, -1.0))),
+            ),
+        ])
+    }
+
+    // A grid of small circles with varying radii
+    fn crowd() -> Compound {
+        (0..20)
+            .flat_map(|i| (0..20).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let radius = 0.1 + 0.02 * ((i * 7 + j * 3) % 10) as Scalar;
+                let offset = vector!(i as Scalar, j as Scalar * 1.5);
+                (
+                    Similarity::from_parts(1.0, 0.0, offset),
+                    Shape::from(Rounded::new(Shape::from(Point::ORIGIN), radius)),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn signed_distance() {
+        let vehicle = vehicle();
+
+        assert_approx_eq!(vehicle.signed_distance(Point::new(-3.0, 0.0)), 2.0);
+        assert_approx_eq!(vehicle.signed_distance(Point::new(4.5, 0.0)), -0.5);
+        assert_approx_eq!(vehicle.signed_distance(Point::new(2.0, 0.5)), 0.5);
+        assert_eq!(
+            Compound::new(Vec::new()).signed_distance(Point::ORIGIN),
+            Scalar::INFINITY
+        );
+    }
+
+    #[test]
+    fn cast_ray() {
+        let vehicle = vehicle();
+
+        let ray = Ray::new(Point::new(-3.0, 0.0), Vector::X);
+        assert_approx_eq!(vehicle.cast_ray(&ray).unwrap(), 2.0);
+
+        let down = Ray::new(Point::new(2.0, 3.0), -Vector::Y);
+        assert_approx_eq!(vehicle.cast_ray(&down).unwrap(), 3.0);
+
+        let miss = Ray::new(Point::new(-3.0, 2.0), Vector::X);
+        assert_eq!(vehicle.cast_ray(&miss), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            vehicle().bounding_box().unwrap(),
+            Rect::new(Point::new(-1.0, -1.0), Point::new(5.0, 1.0))
+        );
+        assert_eq!(Compound::new(Vec::new()).bounding_box(), None);
+    }
+
+    #[test]
+    fn circle_and_thick_polyline() {
+        let track = Compound::new(vec![
+            (
+                Similarity::IDENT,
+                Shape::from(Circle::new(Point::new(0.0, 3.0), 1.0)),
+            ),
+            (
+                Similarity::from_parts(1.0, 0.0, vector!(0.0, -1.0)),
+                Shape::from(Rounded::new(
+                    Shape::from(Chain::new(vec![
+                        Point::new(-2.0, 0.0),
+                        Point::ORIGIN,
+                        Point::new(2.0, 1.0),
+                    ])),
+                    0.5,
+                )),
+            ),
+        ]);
+
+        assert_approx_eq!(track.signed_distance(Point::new(-1.0, 0.0)), 0.5);
+        assert_approx_eq!(track.signed_distance(Point::new(0.0, 3.5)), -0.5);
+
+        let ray = Ray::new(Point::new(0.0, 5.0), -Vector::Y);
+        assert_approx_eq!(track.cast_ray(&ray).unwrap(), 1.0);
+        let past_circle = Ray::new(Point::new(-1.5, 5.0), -Vector::Y);
+        assert_approx_eq!(track.cast_ray(&past_circle).unwrap(), 5.5);
+
+        assert_approx_eq!(
+            track.bounding_box().unwrap(),
+            Rect::new(Point::new(-2.5, -1.5), Point::new(2.5, 4.0))
+        );
+    }
+
+    #[test]
+    fn tilted_ray_hits_point_child() {
+        let point = Point::new(1.0, 2.0);
+        let child_pose = Similarity::from_parts(1.0, 0.3, vector!(0.7, 0.2));
+        let mut beacon = Compound::new(vec![(child_pose.clone(), Shape::from(point))]);
+        beacon.set_pose(Similarity::from_parts(2.0, 1.1, vector!(-3.0, 0.5)));
+
+        let target = &beacon.pose().compose(&child_pose) * point;
+        let origin = Point::new(0.3, -0.4);
+        let ray = Ray::new(origin, (target - origin) * 0.5);
+        assert_approx_eq!(beacon.cast_ray(&ray).unwrap(), 2.0, 4.0 * Scalar::EPSILON);
+
+        let tilted = Ray::new(origin, vector!(ray.direction.x, ray.direction.y + 1e-3));
+        assert_eq!(beacon.cast_ray(&tilted), None);
+    }
+
+    #[test]
+    fn empty_chain_child() {
+        let empty = Shape::from(Chain::new(Vec::new()));
+        assert_eq!(empty.bounding_box(), None);
+
+        let compound = Compound::new(vec![
+            (Similarity::IDENT, empty),
+            (Similarity::IDENT, wheel()),
+        ]);
+        assert_approx_eq!(
+            compound.bounding_box().unwrap(),
+            Rect::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0))
+        );
+        assert_approx_eq!(compound.signed_distance(Point::new(3.0, 0.0)), 2.0);
+    }
+
+    #[test]
+    fn transform_moves_children_together() {
+        let mut vehicle = vehicle();
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let point = Point::new(2.0, 0.5);
+        let distance = vehicle.signed_distance(point);
+
+        vehicle *= &t;
+        assert_approx_eq!(
+            vehicle.signed_distance(&t * point),
+            2.0 * distance,
+            2.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            vehicle.bounding_box().unwrap(),
+            Rect::new(Point::new(-1.0, -2.0), Point::new(3.0, 10.0)),
+            8.0 * Scalar::EPSILON
+        );
+
+        vehicle *= &t;
+        let world: Vec<Shape> = vehicle
+            .children()
+            .iter()
+            .map(|(pose, shape)| shape.clone() * pose * &t * &t)
+            .collect();
+        for point in [Point::new(3.0, 4.0), Point::new(-10.0, 2.0)] {
+            let expected = world
+                .iter()
+                .map(|s| s.distance_to_point(point))
+                .fold(Scalar::INFINITY, Scalar::min);
+            assert_approx_eq!(
+                vehicle.signed_distance(point),
+                expected,
+                8.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn mirror() {
+        let mut vehicle = vehicle();
+        vehicle *= &Similarity::from_parts(2.0, 0.5, vector!(1.0, 3.0));
+        let mirror = Line::new(Point::new(0.0, 1.0), vector!(1.0, 2.0)).reflection();
+        let world: Vec<Shape> = vehicle
+            .children()
+            .iter()
+            .map(|(pose, shape)| shape.clone() * pose * vehicle.pose() * &mirror)
+            .collect();
+
+        vehicle *= &mirror;
+        assert!(vehicle.pose().reflects());
+        for point in [Point::new(3.0, 4.0), Point::new(-10.0, 2.0)] {
+            let expected = world
+                .iter()
+                .map(|s| s.distance_to_point(point))
+                .fold(Scalar::INFINITY, Scalar::min);
+            assert_approx_eq!(
+                vehicle.signed_distance(point),
+                expected,
+                16.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut crowd = crowd();
+        crowd.set_pose(Similarity::from_parts(0.5, 1.0, vector!(3.0, -2.0)));
+        let world: Vec<Shape> = crowd
+            .children()
+            .iter()
+            .map(|(pose, shape)| shape.clone() * pose * crowd.pose())
+            .collect();
+
+        for point in [
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 5.0),
+            Point::new(-20.0, 3.0),
+            Point::new(2.3, 7.1),
+        ] {
+            let expected = world
+                .iter()
+                .map(|s| s.distance_to_point(point))
+                .fold(Scalar::INFINITY, Scalar::min);
+            assert_approx_eq!(
+                crowd.signed_distance(point),
+                expected,
+                16.0 * Scalar::EPSILON
+            );
+
+            let ray = Ray::new(point, vector!(0.3, 1.0));
+            let expected = world
+                .iter()
+                .filter_map(|s| s.cast_ray(&ray))
+                .min_by(Scalar::total_cmp);
+            match (crowd.cast_ray(&ray), expected) {
+                (Some(t), Some(expected)) => {
+                    assert_approx_eq!(t, expected, 128.0 * Scalar::EPSILON)
+                }
+                (t, expected) => assert_eq!(t, expected),
+            }
+        }
+    }
+
+    #[bench]
+    fn signed_distance_bench(b: &mut Bencher) {
+        let crowd = crowd();
+        let point = Point::new(7.3, 11.2);
+
+        b.iter(|| black_box(crowd.signed_distance(point)));
+    }
+
+    #[bench]
+    fn cast_ray_bench(b: &mut Bencher) {
+        let crowd = crowd();
+        let ray = Ray::new(Point::new(-5.0, 3.3), vector!(1.0, 0.1));
+
+        b.iter(|| black_box(crowd.cast_ray(&ray)));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::geometry::Scalar;
 
-use super::{Chain, Point, Segment};
+use super::{Capsule, Chain, Point, Segment};
 
 pub fn point_to_point(a: Point, b: Point) -> Scalar {
     (b - a).norm()
@@ -11,6 +11,10 @@ pub fn point_to_segment(p: Point, segment: &Segment) -> Scalar {
 }
 
 pub fn segment_to_segment(s1: &Segment, s2: &Segment) -> Scalar {
+    // The end point distances miss segments that cross
+    if s1.intersects(s2) {
+        return 0.0;
+    }
     let d1 = s1.distance_to_point(s2.start);
     let d2 = s1.distance_to_point(s2.end);
     let d3 = s2.distance_to_point(s1.start);
@@ -18,6 +22,18 @@ pub fn segment_to_segment(s1: &Segment, s2: &Segment) -> Scalar {
     d1.min(d2).min(d3).min(d4)
 }
 
+pub fn point_to_capsule(p: Point, capsule: &Capsule) -> Scalar {
+    capsule.signed_distance(p)
+}
+
+pub fn segment_to_capsule(s: &Segment, capsule: &Capsule) -> Scalar {
+    segment_to_segment(s, &capsule.segment) - capsule.radius
+}
+
+pub fn capsule_to_capsule(c1: &Capsule, c2: &Capsule) -> Scalar {
+    segment_to_segment(&c1.segment, &c2.segment) - c1.radius - c2.radius
+}
+
 pub fn point_to_chain(p: Point, chain: &Chain) -> Scalar {
     chain.distance_to_point(p)
 }
@@ -80,6 +96,75 @@ mod tests {
         assert_approx_eq!(super::segment_to_segment(&s1, &s2), 1.0 / Scalar::sqrt(5.0));
     }
 
+    #[test]
+    fn segment_to_segment_crossing() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 2.0));
+        let s2 = Segment::new(Point::new(1.0, 3.0), Point::new(3.0, -1.0));
+        assert_eq!(super::segment_to_segment(&s1, &s2), 0.0);
+    }
+
+    #[test]
+    fn point_to_capsule() {
+        let capsule = Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        assert_approx_eq!(
+            super::point_to_capsule(Point::new(2.0, -3.0), &capsule),
+            2.0
+        );
+    }
+
+    #[test]
+    fn segment_to_capsule() {
+        let capsule = Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        let s = Segment::new(Point::new(6.0, -1.0), Point::new(6.0, 1.0));
+        assert_approx_eq!(super::segment_to_capsule(&s, &capsule), 1.0);
+    }
+
+    #[test]
+    fn capsule_to_capsule() {
+        let c1 = Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        let c2 = Capsule::new(
+            Segment::new(Point::new(2.0, 3.0), Point::new(2.0, 5.0)),
+            0.5,
+        );
+        assert_approx_eq!(super::capsule_to_capsule(&c1, &c2), 1.5);
+    }
+
+    #[test]
+    fn capsule_to_capsule_overlapping() {
+        let c1 = Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        let c2 = Capsule::new(
+            Segment::new(Point::new(2.0, 0.5), Point::new(2.0, 2.0)),
+            1.0,
+        );
+        assert_approx_eq!(super::capsule_to_capsule(&c1, &c2), -1.5);
+    }
+
+    #[test]
+    fn capsule_to_capsule_crossing() {
+        let c1 = Capsule::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        let c2 = Capsule::new(
+            Segment::new(Point::new(2.0, -3.0), Point::new(2.0, 3.0)),
+            0.5,
+        );
+        assert_approx_eq!(super::capsule_to_capsule(&c1, &c2), -1.5);
+        assert_approx_eq!(super::segment_to_capsule(&c2.segment, &c1), -1.0);
+    }
+
     #[test]
     fn point_to_chain() {
         let p = Point::new(3.0, 2.0);
@@ -101,6 +186,18 @@ mod tests {
             Point::new(4.0, 3.0),
             Point::new(5.0, 2.0),
         ]);
+        // The segment crosses the first edge of the chain
+        assert_eq!(super::segment_to_chain(&s, &c), 0.0);
+    }
+
+    #[test]
+    fn segment_to_chain_apart() {
+        let s = Segment::new(Point::new(1.0, 1.0), Point::new(2.0, 3.0));
+        let c = Chain::new(vec![
+            Point::new(2.0, 2.0),
+            Point::new(4.0, 3.0),
+            Point::new(5.0, 2.0),
+        ]);
         assert_approx_eq!(super::segment_to_chain(&s, &c), 1.0 / Scalar::sqrt(5.0));
     }
 
@@ -118,7 +215,8 @@ mod tests {
             Point::new(4.0, 3.0),
             Point::new(5.0, 2.0),
         ]);
-        assert_approx_eq!(super::chain_to_chain(&c1, &c2), 1.0 / Scalar::sqrt(5.0));
+        // The chains cross
+        assert_eq!(super::chain_to_chain(&c1, &c2), 0.0);
     }
 
     #[bench]
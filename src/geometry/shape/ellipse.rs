@@ -0,0 +1,442 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        transform::{Affine, ScaledRotation, Similarity},
+        vector, Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Circle, Point, Ray, Rect, SignedDistance};
+
+const MAX_ROOT_ITERATIONS: usize = 200;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ellipse {
+    pub center: Point,
+    pub semi_axes: Vector,
+    pub rotation: Scalar,
+}
+
+impl Ellipse {
+    pub const fn new(center: Point, semi_axes: Vector, rotation: Scalar) -> Self {
+        Self {
+            center,
+            semi_axes,
+            rotation,
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let local = self.to_local(point);
+        (local.x / self.semi_axes.x).powi(2) + (local.y / self.semi_axes.y).powi(2) <= 1.0
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        let local = self.to_local(point);
+        let closest =
+            closest_in_first_quadrant(self.semi_axes, vector!(local.x.abs(), local.y.abs()));
+        self.to_world(vector!(
+            closest.x.copysign(local.x),
+            closest.y.copysign(local.y)
+        ))
+    }
+
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        let distance = point.distance(self.closest_point(point));
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (a, b) = (self.semi_axes.x, self.semi_axes.y);
+        Rect::from_center(
+            self.center,
+            (a * a * cos * cos + b * b * sin * sin).sqrt(),
+            (a * a * sin * sin + b * b * cos * cos).sqrt(),
+        )
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        // Scaling the local frame by the semi-axes turns the ellipse into the unit circle
+        let origin = self.to_local(ray.origin);
+        let direction = ScaledRotation::from_parts(1.0, -self.rotation).apply(ray.direction);
+        let origin = vector!(origin.x / self.semi_axes.x, origin.y / self.semi_axes.y);
+        let direction = vector!(
+            direction.x / self.semi_axes.x,
+            direction.y / self.semi_axes.y
+        );
+
+        let a = direction.norm_sq();
+        let b = origin.dot(direction);
+        let c = origin.norm_sq() - 1.0;
+        let discriminant = b * b - a * c;
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        [(-b - root) / a, (-b + root) / a]
+            .into_iter()
+            .find(|&t| t >= 0.0)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.center *= t;
+        self.semi_axes *= t.scale();
+        // An ellipse is symmetric about its own axes, so mirroring it only negates its rotation
+        self.rotation = if t.reflects() {
+            t.rotation() - self.rotation
+        } else {
+            self.rotation + t.rotation()
+        };
+    }
+
+    fn to_local(&self, point: Point) -> Vector {
+        ScaledRotation::from_parts(1.0, -self.rotation).apply(point - self.center)
+    }
+
+    fn to_world(&self, local: Vector) -> Point {
+        self.center + ScaledRotation::from_parts(1.0, self.rotation).apply(local)
+    }
+}
+
+// Closest point on the axis-aligned ellipse with the given semi-axes to a point with
+// non-negative coordinates, following Eberly's robust bisection method.
+fn closest_in_first_quadrant(semi_axes: Vector, point: Vector) -> Vector {
+    if semi_axes.x < semi_axes.y {
+        let swapped =
+            closest_in_first_quadrant(vector!(semi_axes.y, semi_axes.x), vector!(point.y, point.x));
+        return vector!(swapped.y, swapped.x);
+    }
+
+    let (e0, e1) = (semi_axes.x, semi_axes.y);
+    let (y0, y1) = (point.x, point.y);
+    if y1 > 0.0 {
+        if y0 > 0.0 {
+            let (z0, z1) = (y0 / e0, y1 / e1);
+            let g = z0 * z0 + z1 * z1 - 1.0;
+            if g == 0.0 {
+                return point;
+            }
+            let r0 = (e0 / e1).powi(2);
+            let s = root_of_distance_equation(r0, z0, z1, g);
+            return vector!(r0 * y0 / (s + r0), y1 / (s + 1.0));
+        }
+        return vector!(0.0, e1);
+    }
+
+    let numerator = e0 * y0;
+    let denominator = e0 * e0 - e1 * e1;
+    if numerator < denominator {
+        let ratio = numerator / denominator;
+        vector!(e0 * ratio, e1 * (1.0 - ratio * ratio).sqrt())
+    } else {
+        vector!(e0, 0.0)
+    }
+}
+
+fn root_of_distance_equation(r0: Scalar, z0: Scalar, z1: Scalar, g: Scalar) -> Scalar {
+    let n0 = r0 * z0;
+    let mut s0 = z1 - 1.0;
+    let mut s1 = if g < 0.0 {
+        0.0
+    } else {
+        vector!(n0, z1).norm() - 1.0
+    };
+
+    let mut s = 0.0;
+    for _ in 0..MAX_ROOT_ITERATIONS {
+        s = (s0 + s1) / 2.0;
+        if s == s0 || s == s1 {
+            break;
+        }
+
+        let g = (n0 / (s + r0)).powi(2) + (z1 / (s + 1.0)).powi(2) - 1.0;
+        if g > 0.0 {
+            s0 = s;
+        } else if g < 0.0 {
+            s1 = s;
+        } else {
+            break;
+        }
+    }
+    s
+}
+
+impl SignedDistance for Ellipse {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Ellipse::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for Ellipse {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && self.semi_axes.approx_eq(&other.semi_axes, epsilon)
+            && self.rotation.approx_eq(&other.rotation, epsilon)
+    }
+}
+
+impl Mul<Ellipse> for &Similarity {
+    type Output = Ellipse;
+
+    fn mul(self, mut rhs: Ellipse) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Ellipse {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl Mul<Ellipse> for &Affine {
+    type Output = Ellipse;
+
+    fn mul(self, rhs: Ellipse) -> Self::Output {
+        // The outline is the unit circle under the matrix with columns `u` and `v`. Its axes
+        // are the eigenvectors of that matrix times its transpose.
+        let rotation = ScaledRotation::from_parts(1.0, rhs.rotation);
+        let u = self.apply_linear(rotation.apply(vector!(rhs.semi_axes.x, 0.0)));
+        let v = self.apply_linear(rotation.apply(vector!(0.0, rhs.semi_axes.y)));
+        let xx = u.x * u.x + v.x * v.x;
+        let xy = u.x * u.y + v.x * v.y;
+        let yy = u.y * u.y + v.y * v.y;
+
+        let mean = (xx + yy) / 2.0;
+        let spread = vector!((xx - yy) / 2.0, xy).norm();
+        Ellipse::new(
+            self * rhs.center,
+            vector!((mean + spread).sqrt(), (mean - spread).max(0.0).sqrt()),
+            Scalar::atan2(2.0 * xy, xx - yy) / 2.0,
+        )
+    }
+}
+
+impl From<Circle> for Ellipse {
+    fn from(value: Circle) -> Self {
+        Self::new(value.center, vector!(value.radius, value.radius), 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::scalar::consts::{FRAC_PI_2, FRAC_PI_4, TAU},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn ellipse() -> Ellipse {
+        Ellipse::new(Point::new(1.0, 2.0), vector!(3.0, 1.0), FRAC_PI_2)
+    }
+
+    // Brute force over a fine sampling of the outline
+    fn sampled_distance(ellipse: &Ellipse, point: Point) -> Scalar {
+        (0..100_000)
+            .map(|i| {
+                let angle = TAU * i as Scalar / 100_000.0;
+                let local = vector!(
+                    ellipse.semi_axes.x * angle.cos(),
+                    ellipse.semi_axes.y * angle.sin()
+                );
+                point.distance(ellipse.to_world(local))
+            })
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+
+    #[test]
+    fn contains() {
+        let ellipse = ellipse();
+
+        assert!(ellipse.contains(Point::new(1.0, 4.5)));
+        assert!(ellipse.contains(Point::new(1.5, 2.0)));
+        assert!(!ellipse.contains(Point::new(2.5, 2.0)));
+        assert!(!ellipse.contains(Point::new(1.0, 5.5)));
+    }
+
+    #[test]
+    fn signed_distance_on_axes() {
+        let ellipse = ellipse();
+
+        assert_approx_eq!(ellipse.signed_distance(Point::new(1.0, 7.0)), 2.0);
+        assert_approx_eq!(ellipse.signed_distance(Point::new(4.0, 2.0)), 2.0);
+        assert_approx_eq!(ellipse.signed_distance(Point::new(1.0, 2.0)), -1.0);
+    }
+
+    #[test]
+    fn signed_distance_matches_sampling() {
+        let ellipse = Ellipse::new(Point::new(-1.0, 0.5), vector!(4.0, 1.5), 0.3);
+
+        for point in [
+            Point::new(3.0, 4.0),
+            Point::new(-6.0, -2.0),
+            Point::new(0.0, 0.0),
+            Point::new(-1.0, 0.6),
+            Point::new(2.5, 1.5),
+        ] {
+            assert_approx_eq!(
+                ellipse.signed_distance(point).abs(),
+                sampled_distance(&ellipse, point),
+                1e-6 + 16.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn closest_point_inside_on_major_axis() {
+        let ellipse = Ellipse::new(Point::ORIGIN, vector!(2.0, 1.0), 0.0);
+        let closest = ellipse.closest_point(Point::new(1.0, 0.0));
+
+        assert_approx_eq!(
+            closest,
+            Point::new(4.0 / 3.0, Scalar::sqrt(5.0) / 3.0),
+            2.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn closest_point_circle() {
+        let ellipse = Ellipse::new(Point::new(1.0, 1.0), vector!(2.0, 2.0), 0.7);
+        assert_approx_eq!(
+            ellipse.closest_point(Point::new(4.0, 5.0)),
+            Point::new(2.2, 2.6),
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            ellipse().bounding_box(),
+            Rect::new(Point::new(0.0, -1.0), Point::new(2.0, 5.0))
+        );
+
+        let rotated = Ellipse::new(Point::ORIGIN, vector!(2.0, 1.0), FRAC_PI_4);
+        let half = Scalar::sqrt(2.5);
+        assert_approx_eq!(
+            rotated.bounding_box(),
+            Rect::new(Point::new(-half, -half), Point::new(half, half)),
+            2.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn cast_ray() {
+        let ellipse = ellipse();
+
+        let ray = Ray::new(Point::new(1.0, -3.0), Vector::Y);
+        assert_approx_eq!(ellipse.cast_ray(&ray).unwrap(), 2.0, 4.0 * Scalar::EPSILON);
+
+        let inside = Ray::new(Point::new(1.0, 2.0), vector!(2.0, 0.0));
+        assert_approx_eq!(ellipse.cast_ray(&inside).unwrap(), 0.5);
+
+        let away = Ray::new(Point::new(1.0, -3.0), -Vector::Y);
+        assert_eq!(ellipse.cast_ray(&away), None);
+
+        let miss = Ray::new(Point::new(3.0, -3.0), Vector::Y);
+        assert_eq!(ellipse.cast_ray(&miss), None);
+    }
+
+    #[test]
+    fn transform() {
+        let mut ellipse = ellipse();
+        ellipse *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(2.0, -1.0));
+
+        assert_approx_eq!(
+            ellipse,
+            Ellipse::new(Point::new(-2.0, 1.0), vector!(6.0, 2.0), TAU / 2.0),
+            8.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn transform_preserves_distance() {
+        let ellipse = Ellipse::new(Point::new(-1.0, 0.5), vector!(4.0, 1.5), 0.3);
+        let t = Similarity::from_parts(0.5, 1.2, vector!(3.0, -2.0));
+        let point = Point::new(3.0, 4.0);
+
+        assert_approx_eq!(
+            (&t * ellipse.clone()).signed_distance(&t * point),
+            0.5 * ellipse.signed_distance(point),
+            16.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn mirror() {
+        let ellipse = Ellipse::new(Point::new(-1.0, 0.5), vector!(4.0, 1.5), 0.3);
+        let t = Similarity::from_reflection(1.0);
+        let mirrored = &t * ellipse.clone();
+
+        for point in [
+            Point::new(3.0, 4.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.5, 1.5),
+        ] {
+            assert_approx_eq!(
+                mirrored.signed_distance(&t * point),
+                ellipse.signed_distance(point),
+                8.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn affine_transform() {
+        let circle = Ellipse::from(Circle::new(Point::new(1.0, 0.0), 1.0));
+        let stretch = Affine::from(ScaledRotation::from_parts(1.0, FRAC_PI_2))
+            .compose(&Affine::from_scale(vector!(3.0, 1.0)));
+        let stretched = &stretch * circle;
+        assert_approx_eq!(
+            stretched.center,
+            Point::new(0.0, 3.0),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            stretched.semi_axes,
+            vector!(3.0, 1.0),
+            8.0 * Scalar::EPSILON
+        );
+        // The rotation is only defined up to a half turn
+        assert_approx_eq!(stretched.rotation.cos(), 0.0, 8.0 * Scalar::EPSILON);
+
+        // Every point of the outline has to land on the outline of the result
+        let ellipse = Ellipse::new(Point::new(-1.0, 0.5), vector!(4.0, 1.5), 0.3);
+        let affine = Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+        let mapped = &affine * ellipse.clone();
+        for i in 0..16 {
+            let angle = TAU * i as Scalar / 16.0;
+            let outline = ellipse.to_world(vector!(
+                ellipse.semi_axes.x * angle.cos(),
+                ellipse.semi_axes.y * angle.sin()
+            ));
+            assert_approx_eq!(
+                mapped.signed_distance(&affine * outline),
+                0.0,
+                64.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[bench]
+    fn signed_distance_bench(b: &mut Bencher) {
+        let ellipse = Ellipse::new(Point::new(-1.0, 0.5), vector!(4.0, 1.5), 0.3);
+        let point = Point::new(3.0, 4.0);
+
+        b.iter(|| black_box(ellipse.signed_distance(point)));
+    }
+}
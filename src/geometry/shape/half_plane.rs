@@ -0,0 +1,336 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar, Vector},
+    utils::approx::ApproxEq,
+};
+
+use super::{Chain, Line, Point, Ray, SignedDistance};
+
+// Everything on the opposite side of `normal` from the boundary through `point`
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalfPlane {
+    pub point: Point,
+    pub normal: Vector,
+}
+
+impl HalfPlane {
+    pub const fn new(point: Point, normal: Vector) -> Self {
+        Self { point, normal }
+    }
+
+    // The half-plane to the left of the line
+    pub fn left_of(line: &Line) -> Self {
+        Self::new(line.point, -line.direction.perp())
+    }
+
+    // Directed so that the inside is on its left
+    pub fn boundary(&self) -> Line {
+        Line::new(self.point, self.normal.perp())
+    }
+
+    // Negative inside
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        (point - self.point).dot(self.normal) / self.normal.norm()
+    }
+
+    // Boundary points count as inside
+    pub fn contains(&self, point: Point) -> bool {
+        (point - self.point).dot(self.normal) <= 0.0
+    }
+
+    pub fn project(&self, point: Point) -> Point {
+        if self.contains(point) {
+            return point;
+        }
+        self.boundary().closest_point(point)
+    }
+
+    // Parameter of the first point at or after the ray origin that lies inside
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        if self.contains(ray.origin) {
+            return Some(0.0);
+        }
+        ray.line_intersection(&self.boundary())
+    }
+
+    // Pieces of the chain that lie inside, in order
+    pub fn clip_chain(&self, chain: &Chain) -> Vec<Chain> {
+        if chain.num_vertices() == 1 {
+            return if self.contains(chain.vertices[0]) {
+                vec![chain.clone()]
+            } else {
+                Vec::new()
+            };
+        }
+
+        let mut pieces = Vec::new();
+        let mut current: Vec<Point> = Vec::new();
+        for segment in chain.segments() {
+            let d1 = self.offset(segment.start);
+            let d2 = self.offset(segment.end);
+            match (d1 <= 0.0, d2 <= 0.0) {
+                (true, true) => {
+                    if current.is_empty() {
+                        current.push(segment.start);
+                    }
+                    current.push(segment.end);
+                }
+                (true, false) => {
+                    if current.is_empty() {
+                        current.push(segment.start);
+                    }
+                    current.push(segment.point_at(d1 / (d1 - d2)));
+                    pieces.push(Chain::new(current.split_off(0)));
+                }
+                (false, true) => {
+                    current.push(segment.point_at(d1 / (d1 - d2)));
+                    current.push(segment.end);
+                }
+                (false, false) => {}
+            }
+        }
+        pieces.push(Chain::new(current));
+
+        pieces
+            .into_iter()
+            .map(|mut piece| {
+                piece.vertices.dedup();
+                piece
+            })
+            .filter(|piece| piece.num_vertices() > 1)
+            .collect()
+    }
+
+    // Sutherland-Hodgman clipping of a polygon ring, which may be given closed or open. The
+    // result is closed, or empty when nothing of the polygon is inside.
+    pub fn clip_polygon(&self, polygon: &Chain) -> Chain {
+        let ring = if polygon.is_closed() {
+            &polygon.vertices[..polygon.num_vertices() - 1]
+        } else {
+            &polygon.vertices[..]
+        };
+
+        let mut clipped = Vec::with_capacity(ring.len() + 2);
+        for (i, &start) in ring.iter().enumerate() {
+            let end = ring[(i + 1) % ring.len()];
+            let d1 = self.offset(start);
+            let d2 = self.offset(end);
+            if d1 <= 0.0 {
+                clipped.push(start);
+            }
+            if (d1 <= 0.0) != (d2 <= 0.0) {
+                clipped.push(start + (end - start) * (d1 / (d1 - d2)));
+            }
+        }
+
+        clipped.dedup();
+        if clipped.len() > 1 && clipped.first() == clipped.last() {
+            clipped.pop();
+        }
+        if clipped.len() < 3 {
+            return Chain::new(Vec::new());
+        }
+        clipped.push(clipped[0]);
+        Chain::new(clipped)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.point *= t;
+        self.normal = t.apply_linear(self.normal);
+    }
+
+    // Signed distance scaled by the length of the normal
+    fn offset(&self, point: Point) -> Scalar {
+        (point - self.point).dot(self.normal)
+    }
+}
+
+impl SignedDistance for HalfPlane {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        HalfPlane::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for HalfPlane {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.point.approx_eq(&other.point, epsilon) && self.normal.approx_eq(&other.normal, epsilon)
+    }
+}
+
+impl Mul<HalfPlane> for &Similarity {
+    type Output = HalfPlane;
+
+    fn mul(self, mut rhs: HalfPlane) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for HalfPlane {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    // Everything below y = 2
+    fn floor() -> HalfPlane {
+        HalfPlane::new(Point::new(0.0, 2.0), vector!(0.0, 3.0))
+    }
+
+    #[test]
+    fn signed_distance() {
+        let floor = floor();
+
+        assert_approx_eq!(floor.signed_distance(Point::new(5.0, 5.0)), 3.0);
+        assert_approx_eq!(floor.signed_distance(Point::new(-1.0, 0.5)), -1.5);
+        assert!(floor.contains(Point::new(7.0, 2.0)));
+        assert!(!floor.contains(Point::new(7.0, 2.1)));
+    }
+
+    #[test]
+    fn left_of() {
+        let line = Line::new(Point::new(0.0, 2.0), vector!(-1.0, 0.0));
+        let half_plane = HalfPlane::left_of(&line);
+
+        assert!(half_plane.contains(Point::new(3.0, 1.0)));
+        assert_approx_eq!(half_plane.signed_distance(Point::new(5.0, 5.0)), 3.0);
+        assert_eq!(
+            half_plane.boundary().side(Point::new(3.0, 1.0)),
+            line.side(Point::new(3.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn project() {
+        let floor = floor();
+
+        assert_approx_eq!(floor.project(Point::new(5.0, 5.0)), Point::new(5.0, 2.0));
+        assert_approx_eq!(floor.project(Point::new(5.0, 1.0)), Point::new(5.0, 1.0));
+    }
+
+    #[test]
+    fn cast_ray() {
+        let floor = floor();
+
+        let falling = Ray::new(Point::new(1.0, 6.0), vector!(1.0, -2.0));
+        assert_approx_eq!(floor.cast_ray(&falling).unwrap(), 2.0);
+
+        let rising = Ray::new(Point::new(1.0, 6.0), vector!(1.0, 2.0));
+        assert_eq!(floor.cast_ray(&rising), None);
+
+        let inside = Ray::new(Point::new(1.0, 0.0), vector!(1.0, 2.0));
+        assert_eq!(floor.cast_ray(&inside), Some(0.0));
+    }
+
+    #[test]
+    fn clip_chain() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 4.0),
+            Point::new(4.0, 0.0),
+            Point::new(5.0, 1.0),
+            Point::new(6.0, 5.0),
+            Point::new(7.0, 5.0),
+        ]);
+        let pieces = floor().clip_chain(&chain);
+
+        assert_eq!(pieces.len(), 2);
+        assert_approx_eq!(
+            pieces[0],
+            Chain::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 2.0)])
+        );
+        assert_approx_eq!(
+            pieces[1],
+            Chain::new(vec![
+                Point::new(3.0, 2.0),
+                Point::new(4.0, 0.0),
+                Point::new(5.0, 1.0),
+                Point::new(5.25, 2.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn clip_chain_touching() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 4.0),
+            Point::new(1.0, 2.0),
+            Point::new(2.0, 4.0),
+        ]);
+        assert!(floor().clip_chain(&chain).is_empty());
+    }
+
+    #[test]
+    fn clip_polygon() {
+        let square = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+            Point::new(0.0, 0.0),
+        ]);
+
+        assert_approx_eq!(
+            floor().clip_polygon(&square),
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 2.0),
+                Point::new(0.0, 2.0),
+                Point::new(0.0, 0.0),
+            ])
+        );
+
+        let above = HalfPlane::new(Point::new(0.0, -1.0), vector!(0.0, 1.0));
+        assert_eq!(above.clip_polygon(&square).num_vertices(), 0);
+
+        let open_triangle = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 4.0),
+        ]);
+        let clipped = floor().clip_polygon(&open_triangle);
+        assert!(clipped.is_closed());
+        assert_eq!(clipped.num_vertices(), 5);
+    }
+
+    #[test]
+    fn transform() {
+        let mut floor = floor();
+        floor *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+
+        assert_approx_eq!(
+            floor,
+            HalfPlane::new(Point::new(-3.0, 0.0), vector!(-6.0, 0.0)),
+            8.0 * Scalar::EPSILON
+        );
+        assert!(floor.contains(Point::new(0.0, 10.0)));
+    }
+
+    #[test]
+    fn mirror() {
+        let floor = floor();
+        let t = Similarity::from_reflection(0.4);
+        let mirrored = &t * floor.clone();
+
+        for point in [Point::new(1.0, 3.0), Point::new(-2.0, -1.0)] {
+            assert_approx_eq!(
+                mirrored.signed_distance(&t * point),
+                floor.signed_distance(point),
+                8.0 * Scalar::EPSILON
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        transform::{Similarity, Translation},
+        Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Orientation, Point, Segment};
+
+// Infinite line through `point`. Parameters along it are in units of `direction`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
+    pub point: Point,
+    pub direction: Vector,
+}
+
+impl Line {
+    pub const fn new(point: Point, direction: Vector) -> Self {
+        Self { point, direction }
+    }
+
+    pub fn through(a: Point, b: Point) -> Self {
+        Self::new(a, b - a)
+    }
+
+    pub fn point_at(&self, t: Scalar) -> Point {
+        self.point + self.direction * t
+    }
+
+    pub fn project(&self, point: Point) -> Scalar {
+        let length_sq = self.direction.norm_sq();
+        if length_sq == 0.0 {
+            return 0.0;
+        }
+        (point - self.point).dot(self.direction) / length_sq
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.point_at(self.project(point))
+    }
+
+    // Positive to the left of the direction
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        self.direction.perp_dot(point - self.point) / self.direction.norm()
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        self.signed_distance(point).abs()
+    }
+
+    // Counterclockwise means the point is to the left of the direction
+    pub fn side(&self, point: Point) -> Orientation {
+        let cross = self.direction.perp_dot(point - self.point);
+        if cross > 0.0 {
+            Orientation::CounterClockwise
+        } else if cross < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    // Parameters along both lines of their crossing. Parallel lines have none.
+    pub fn intersection_parameters(&self, other: &Line) -> Option<(Scalar, Scalar)> {
+        let denominator = self.direction.perp_dot(other.direction);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let offset = other.point - self.point;
+        Some((
+            offset.perp_dot(other.direction) / denominator,
+            offset.perp_dot(self.direction) / denominator,
+        ))
+    }
+
+    pub fn intersection(&self, other: &Line) -> Option<Point> {
+        let (t, _) = self.intersection_parameters(other)?;
+        Some(self.point_at(t))
+    }
+
+    pub fn segment_intersection(&self, segment: &Segment) -> Option<Point> {
+        let (t, u) = self.intersection_parameters(&Line::through(segment.start, segment.end))?;
+        (0.0..=1.0).contains(&u).then(|| self.point_at(t))
+    }
+
+    // Mirror image across the line
+    pub fn reflection(&self) -> Similarity {
+        let mirror = Similarity::from_reflection(Scalar::atan2(self.direction.y, self.direction.x));
+        let point = self.point.as_vector();
+        Similarity::new_reflected(
+            mirror.scaled_rotation(),
+            Translation::from(point - mirror.apply(point)),
+        )
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.point *= t;
+        self.direction = t.apply_linear(self.direction);
+    }
+}
+
+impl ApproxEq for Line {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.point.approx_eq(&other.point, epsilon)
+            && self.direction.approx_eq(&other.direction, epsilon)
+    }
+}
+
+impl Mul<Line> for &Similarity {
+    type Output = Line;
+
+    fn mul(self, mut rhs: Line) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Line {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl From<Segment> for Line {
+    fn from(value: Segment) -> Self {
+        Self::through(value.start, value.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn line() -> Line {
+        Line::new(Point::new(1.0, 1.0), vector!(2.0, 0.0))
+    }
+
+    #[test]
+    fn project() {
+        let line = line();
+
+        assert_approx_eq!(line.project(Point::new(4.0, 5.0)), 1.5);
+        assert_approx_eq!(line.project(Point::new(-3.0, 0.0)), -2.0);
+        assert_approx_eq!(
+            line.closest_point(Point::new(-3.0, 0.0)),
+            Point::new(-3.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn signed_distance() {
+        let line = line();
+
+        assert_approx_eq!(line.signed_distance(Point::new(4.0, 5.0)), 4.0);
+        assert_approx_eq!(line.signed_distance(Point::new(-3.0, 0.0)), -1.0);
+        assert_approx_eq!(line.distance_to_point(Point::new(-3.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn side() {
+        let line = line();
+
+        assert_eq!(
+            line.side(Point::new(0.0, 2.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(line.side(Point::new(0.0, 0.0)), Orientation::Clockwise);
+        assert_eq!(line.side(Point::new(7.0, 1.0)), Orientation::Collinear);
+    }
+
+    #[test]
+    fn intersection() {
+        let line = line();
+        let other = Line::through(Point::new(3.0, 0.0), Point::new(5.0, 4.0));
+
+        assert_approx_eq!(line.intersection(&other).unwrap(), Point::new(3.5, 1.0));
+        let (t, u) = line.intersection_parameters(&other).unwrap();
+        assert_approx_eq!(t, 1.25);
+        assert_approx_eq!(u, 0.25);
+        assert_eq!(
+            line.intersection(&Line::new(Point::ORIGIN, Vector::X)),
+            None
+        );
+    }
+
+    #[test]
+    fn segment_intersection() {
+        let line = line();
+
+        let crossing = Segment::new(Point::new(3.0, 0.0), Point::new(5.0, 4.0));
+        assert_approx_eq!(
+            line.segment_intersection(&crossing).unwrap(),
+            Point::new(3.5, 1.0)
+        );
+
+        let short = Segment::new(Point::new(3.0, 2.0), Point::new(5.0, 4.0));
+        assert_eq!(line.segment_intersection(&short), None);
+    }
+
+    #[test]
+    fn transform() {
+        let mut line = line();
+        line *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+
+        assert_approx_eq!(
+            line,
+            Line::new(Point::new(-1.0, 2.0), vector!(0.0, 4.0)),
+            8.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn reflection() {
+        let mirror = Line::through(Point::new(1.0, 0.0), Point::new(2.0, 1.0)).reflection();
+
+        assert!(mirror.reflects());
+        assert_approx_eq!(
+            &mirror * Point::new(0.0, 1.0),
+            Point::new(2.0, -1.0),
+            2.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(&mirror * Point::new(3.0, 2.0), Point::new(3.0, 2.0));
+    }
+}
@@ -1,22 +1,71 @@
+mod arc;
+mod bezier;
+mod capsule;
 mod chain;
 mod circle;
+mod compound;
 mod dist;
+mod ellipse;
+mod half_plane;
+mod line;
+mod multi_polygon;
+mod oriented_rect;
+mod path;
 mod point;
+mod polygon;
+mod ray;
+mod rect;
+mod rounded;
 mod segment;
+mod spline;
+mod sweep;
+mod triangle;
 
 use std::ops::{Mul, MulAssign};
 
-use super::{transform::Similarity, Scalar};
+use super::{
+    transform::{Isometry, Similarity},
+    Scalar,
+};
 
+pub use arc::*;
+pub use bezier::*;
+pub use capsule::*;
 pub use chain::*;
 pub use circle::*;
+pub use compound::*;
+pub use ellipse::*;
+pub use half_plane::*;
+pub use line::*;
+pub use multi_polygon::*;
+pub use oriented_rect::*;
+pub use path::*;
 pub use point::*;
+pub use polygon::*;
+pub use ray::*;
+pub use rect::*;
+pub use rounded::*;
 pub use segment::*;
+pub use spline::*;
+pub use sweep::*;
+pub use triangle::*;
+
+// Largest angle, in radians, between a ray and the direction to a point for the point to count
+// as hit. Exact hits are rare once either has been computed or transformed.
+pub const RAY_POINT_TOLERANCE: Scalar = 64.0 * Scalar::EPSILON;
+
+pub trait SignedDistance {
+    fn signed_distance(&self, point: Point) -> Scalar;
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Shape {
     Point(Point),
     Segment(Segment),
+    Circle(Circle),
+    Capsule(Capsule),
+    Chain(Chain),
+    Rounded(Box<Rounded<Shape>>),
 }
 
 impl Shape {
@@ -24,26 +73,134 @@ impl Shape {
         match self {
             Self::Point(point) => *point *= t,
             Self::Segment(segment) => *segment *= t,
+            Self::Circle(circle) => *circle *= t,
+            Self::Capsule(capsule) => *capsule *= t,
+            Self::Chain(chain) => *chain *= t,
+            Self::Rounded(rounded) => rounded.transform(t),
+        }
+    }
+
+    // Negative inside rounded shapes
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        match self {
+            Self::Point(p) => p.distance(point),
+            Self::Segment(segment) => segment.distance_to_point(point),
+            Self::Circle(circle) => circle.signed_distance(point),
+            Self::Capsule(capsule) => capsule.signed_distance(point),
+            Self::Chain(chain) => chain.distance_to_point(point),
+            Self::Rounded(rounded) => rounded.shape.distance_to_point(point) - rounded.radius,
+        }
+    }
+
+    // None for an empty chain
+    pub fn bounding_box(&self) -> Option<Rect> {
+        match self {
+            Self::Point(p) => Some(Rect::new(*p, *p)),
+            Self::Segment(segment) => Rect::from_points([segment.start, segment.end]),
+            Self::Circle(circle) => Some(circle.bounding_box()),
+            Self::Capsule(capsule) => Some(capsule.bounding_box()),
+            Self::Chain(chain) => Rect::from_points(chain.vertices()),
+            Self::Rounded(rounded) => rounded
+                .shape
+                .bounding_box()
+                .map(|rect| rect.expand(rounded.radius)),
+        }
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        match self {
+            Self::Point(p) => (ray.distance_to_point(*p)
+                <= RAY_POINT_TOLERANCE * ray.origin.distance(*p))
+            .then(|| ray.project(*p)),
+            Self::Segment(segment) => ray.segment_intersection(segment),
+            Self::Circle(circle) => circle.cast_ray(ray),
+            Self::Capsule(capsule) => capsule.cast_ray(ray),
+            Self::Chain(chain) => chain
+                .segments()
+                .filter_map(|segment| ray.segment_intersection(&segment))
+                .min_by(Scalar::total_cmp),
+            // Rounding a point or segment gives a circle or capsule, so nested radii add up
+            Self::Rounded(rounded) => match &rounded.shape {
+                Self::Point(p) => Circle::new(*p, rounded.radius).cast_ray(ray),
+                Self::Segment(segment) => {
+                    Capsule::new(segment.clone(), rounded.radius).cast_ray(ray)
+                }
+                Self::Circle(circle) => {
+                    Circle::new(circle.center, circle.radius + rounded.radius).cast_ray(ray)
+                }
+                Self::Capsule(capsule) => {
+                    Capsule::new(capsule.segment.clone(), capsule.radius + rounded.radius)
+                        .cast_ray(ray)
+                }
+                // Like a compound, the first hit among the capsules along the chain
+                Self::Chain(chain) => chain
+                    .segments()
+                    .filter_map(|segment| Capsule::new(segment, rounded.radius).cast_ray(ray))
+                    .min_by(Scalar::total_cmp),
+                Self::Rounded(inner) => Shape::from(Rounded::new(
+                    inner.shape.clone(),
+                    inner.radius + rounded.radius,
+                ))
+                .cast_ray(ray),
+            },
         }
     }
 
     pub fn signed_distance(&self, other: &Self) -> Scalar {
         match (self, other) {
+            (Self::Rounded(rounded), other) | (other, Self::Rounded(rounded)) => {
+                rounded.shape.signed_distance(other) - rounded.radius
+            }
+            (Self::Circle(circle), other) | (other, Self::Circle(circle)) => {
+                Self::Point(circle.center).signed_distance(other) - circle.radius
+            }
             (Self::Point(a), Self::Point(b)) => dist::point_to_point(*a, *b),
             (Self::Segment(s1), Self::Segment(s2)) => dist::segment_to_segment(s1, s2),
             (Self::Point(p), Self::Segment(segment)) | (Self::Segment(segment), Self::Point(p)) => {
                 dist::point_to_segment(*p, segment)
             }
+            (Self::Capsule(c1), Self::Capsule(c2)) => dist::capsule_to_capsule(c1, c2),
+            (Self::Point(p), Self::Capsule(capsule)) | (Self::Capsule(capsule), Self::Point(p)) => {
+                dist::point_to_capsule(*p, capsule)
+            }
+            (Self::Segment(segment), Self::Capsule(capsule))
+            | (Self::Capsule(capsule), Self::Segment(segment)) => {
+                dist::segment_to_capsule(segment, capsule)
+            }
+            (Self::Chain(c1), Self::Chain(c2)) => dist::chain_to_chain(c1, c2),
+            (Self::Point(p), Self::Chain(chain)) | (Self::Chain(chain), Self::Point(p)) => {
+                dist::point_to_chain(*p, chain)
+            }
+            (Self::Segment(segment), Self::Chain(chain))
+            | (Self::Chain(chain), Self::Segment(segment)) => {
+                dist::segment_to_chain(segment, chain)
+            }
+            (Self::Capsule(capsule), Self::Chain(chain))
+            | (Self::Chain(chain), Self::Capsule(capsule)) => {
+                dist::segment_to_chain(&capsule.segment, chain) - capsule.radius
+            }
         }
     }
 }
 
+impl SignedDistance for Shape {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
 impl MulAssign<&Similarity> for &mut Shape {
     fn mul_assign(&mut self, rhs: &Similarity) {
         self.transform(rhs);
     }
 }
 
+impl MulAssign<&Similarity> for Shape {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
 impl Mul<&Similarity> for Shape {
     type Output = Shape;
 
@@ -53,6 +210,14 @@ impl Mul<&Similarity> for Shape {
     }
 }
 
+impl Mul<Shape> for &Isometry {
+    type Output = Shape;
+
+    fn mul(self, rhs: Shape) -> Self::Output {
+        rhs * &Similarity::from(self.clone())
+    }
+}
+
 impl From<Point> for Shape {
     fn from(value: Point) -> Self {
         Shape::Point(value)
@@ -64,3 +229,27 @@ impl From<Segment> for Shape {
         Shape::Segment(value)
     }
 }
+
+impl From<Circle> for Shape {
+    fn from(value: Circle) -> Self {
+        Shape::Circle(value)
+    }
+}
+
+impl From<Capsule> for Shape {
+    fn from(value: Capsule) -> Self {
+        Shape::Capsule(value)
+    }
+}
+
+impl From<Chain> for Shape {
+    fn from(value: Chain) -> Self {
+        Shape::Chain(value)
+    }
+}
+
+impl From<Rounded<Shape>> for Shape {
+    fn from(value: Rounded<Shape>) -> Self {
+        Shape::Rounded(Box::new(value))
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    iter,
+    ops::{Mul, MulAssign},
+};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{
+    chain_intersections, Chain, FillRule, Point, PolygonError, PolygonWithHoles, Rect,
+    SignedDistance,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MultiPolygonError {
+    Polygon(usize, PolygonError),
+    // Polygons are given by index. A polygon inside the hole of another one does not overlap it.
+    Overlap(usize, usize),
+}
+
+// Disjoint polygons that together form one shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiPolygon {
+    pub polygons: Vec<PolygonWithHoles>,
+}
+
+impl MultiPolygon {
+    pub const fn new(polygons: Vec<PolygonWithHoles>) -> Self {
+        Self { polygons }
+    }
+
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        self.polygons.iter().any(|p| p.contains(point, rule))
+    }
+
+    // Exact as long as the polygons are disjoint
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        self.polygons
+            .iter()
+            .map(|p| p.signed_distance(point))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+
+    pub fn area(&self) -> Scalar {
+        self.polygons.iter().map(|p| p.area()).sum()
+    }
+
+    pub fn perimeter(&self) -> Scalar {
+        self.polygons.iter().map(|p| p.perimeter()).sum()
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.polygons
+            .iter()
+            .filter_map(|p| p.bounding_box())
+            .reduce(|r1, r2| r1.union(&r2))
+    }
+
+    pub fn orient(&mut self) {
+        self.polygons.iter_mut().for_each(|p| p.orient());
+    }
+
+    // Validates every polygon and checks that no two of them overlap
+    pub fn validate(&self) -> Result<(), MultiPolygonError> {
+        for (i, polygon) in self.polygons.iter().enumerate() {
+            polygon
+                .validate()
+                .map_err(|e| MultiPolygonError::Polygon(i, e))?;
+        }
+
+        let outers: Vec<Chain> = self.polygons.iter().map(|p| p.outer.clone()).collect();
+        if let Some(intersection) = chain_intersections(&outers).first() {
+            return Err(MultiPolygonError::Overlap(
+                intersection.first.0,
+                intersection.second.0,
+            ));
+        }
+
+        // Without crossings, one vertex decides whether a whole polygon lies inside another
+        for (i, polygon) in self.polygons.iter().enumerate() {
+            let vertex = polygon.outer.vertices[0];
+            for (j, other) in self.polygons.iter().enumerate() {
+                if i != j && other.contains(vertex, FillRule::EvenOdd) {
+                    return Err(MultiPolygonError::Overlap(j, i));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.polygons.iter_mut().for_each(|p| *p *= t);
+    }
+}
+
+impl SignedDistance for MultiPolygon {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        MultiPolygon::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for MultiPolygon {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.polygons.len() == other.polygons.len()
+            && iter::zip(&self.polygons, &other.polygons).all(|(p1, p2)| p1.approx_eq(p2, epsilon))
+    }
+}
+
+impl Mul<MultiPolygon> for &Similarity {
+    type Output = MultiPolygon;
+
+    fn mul(self, mut rhs: MultiPolygon) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for MultiPolygon {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl FromIterator<PolygonWithHoles> for MultiPolygon {
+    fn from_iter<T: IntoIterator<Item = PolygonWithHoles>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn square(min: (Scalar, Scalar), size: Scalar) -> Chain {
+        Rect::new(
+            Point::new(min.0, min.1),
+            Point::new(min.0 + size, min.1 + size),
+        )
+        .outline()
+    }
+
+    // A 6 by 6 square with a 4 by 4 hole, an island inside the hole and a separate square
+    fn islands() -> MultiPolygon {
+        MultiPolygon::new(vec![
+            PolygonWithHoles::new(square((0.0, 0.0), 6.0), vec![square((1.0, 1.0), 4.0)]),
+            PolygonWithHoles::from(square((2.0, 2.0), 2.0)),
+            PolygonWithHoles::from(square((10.0, 0.0), 1.0)),
+        ])
+    }
+
+    #[test]
+    fn contains() {
+        let islands = islands();
+
+        assert!(islands.contains(Point::new(0.5, 3.0), FillRule::EvenOdd));
+        assert!(!islands.contains(Point::new(1.5, 3.0), FillRule::EvenOdd));
+        assert!(islands.contains(Point::new(3.0, 3.0), FillRule::EvenOdd));
+        assert!(islands.contains(Point::new(10.5, 0.5), FillRule::NonZero));
+        assert!(!islands.contains(Point::new(8.0, 0.5), FillRule::NonZero));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let islands = islands();
+
+        assert_approx_eq!(islands.signed_distance(Point::new(3.0, 3.0)), -1.0);
+        assert_approx_eq!(islands.signed_distance(Point::new(1.5, 3.0)), 0.5);
+        assert_approx_eq!(islands.signed_distance(Point::new(8.0, 0.5)), 2.0);
+    }
+
+    #[test]
+    fn measures() {
+        let islands = islands();
+
+        assert_approx_eq!(islands.area(), 25.0);
+        assert_approx_eq!(islands.perimeter(), 52.0);
+        assert_approx_eq!(
+            islands.bounding_box().unwrap(),
+            Rect::new(Point::ORIGIN, Point::new(11.0, 6.0))
+        );
+        assert_eq!(MultiPolygon::new(Vec::new()).bounding_box(), None);
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(islands().validate(), Ok(()));
+
+        let mut overlapping = islands();
+        overlapping
+            .polygons
+            .push(PolygonWithHoles::from(square((0.5, 0.5), 0.2)));
+        assert_eq!(
+            overlapping.validate(),
+            Err(MultiPolygonError::Overlap(0, 3))
+        );
+
+        let mut crossing = islands();
+        crossing
+            .polygons
+            .push(PolygonWithHoles::from(square((10.5, 0.5), 1.0)));
+        assert!(matches!(
+            crossing.validate(),
+            Err(MultiPolygonError::Overlap(2, 3) | MultiPolygonError::Overlap(3, 2))
+        ));
+
+        let mut invalid = islands();
+        invalid.polygons[2].holes.push(square((20.0, 0.0), 1.0));
+        assert_eq!(
+            invalid.validate(),
+            Err(MultiPolygonError::Polygon(2, PolygonError::HoleOutside(0)))
+        );
+    }
+
+    #[test]
+    fn transform() {
+        let mut islands = islands();
+        islands *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+
+        assert_approx_eq!(islands.area(), 100.0);
+        assert!(islands.contains(Point::new(-5.0, 5.0), FillRule::EvenOdd));
+        assert!(!islands.contains(Point::new(-2.0, 5.0), FillRule::EvenOdd));
+    }
+}
@@ -0,0 +1,295 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        transform::{ScaledRotation, Similarity},
+        Scalar, Vector,
+    },
+    utils::approx::ApproxEq,
+};
+
+use super::{Chain, Point, Ray, Rect, SignedDistance};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrientedRect {
+    pub center: Point,
+    pub half_extents: Vector,
+    pub rotation: Scalar,
+}
+
+impl OrientedRect {
+    pub const fn new(center: Point, half_extents: Vector, rotation: Scalar) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    // Counterclockwise, starting at the corner that is `min` in the local frame
+    pub fn corners(&self) -> [Point; 4] {
+        self.local_rect().corners().map(|c| self.to_world(c))
+    }
+
+    // Closed chain around the boundary
+    pub fn outline(&self) -> Chain {
+        let corners = self.corners();
+        corners.iter().chain(&corners[..1]).copied().collect()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.local_rect().contains(self.to_local(point))
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.to_world(self.local_rect().closest_point(self.to_local(point)))
+    }
+
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        self.local_rect().signed_distance(self.to_local(point))
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        Rect::from_points(self.corners()).unwrap()
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        // Rotating into the local frame keeps ray parameters unchanged
+        let local = Ray::new(
+            self.to_local(ray.origin),
+            ScaledRotation::from_parts(1.0, -self.rotation).apply(ray.direction),
+        );
+        self.local_rect().cast_ray(&local)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.center *= t;
+        self.half_extents *= t.scale();
+        // Mirroring the rectangle maps its axes onto the reflected axes, so only the rotation
+        // angle is negated
+        self.rotation = if t.reflects() {
+            t.rotation() - self.rotation
+        } else {
+            self.rotation + t.rotation()
+        };
+    }
+
+    fn local_rect(&self) -> Rect {
+        Rect::from_center(Point::ORIGIN, self.half_extents.x, self.half_extents.y)
+    }
+
+    fn to_local(&self, point: Point) -> Point {
+        Point::from(ScaledRotation::from_parts(1.0, -self.rotation).apply(point - self.center))
+    }
+
+    fn to_world(&self, local: Point) -> Point {
+        self.center + ScaledRotation::from_parts(1.0, self.rotation).apply(local.as_vector())
+    }
+}
+
+impl SignedDistance for OrientedRect {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        OrientedRect::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for OrientedRect {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && self.half_extents.approx_eq(&other.half_extents, epsilon)
+            && self.rotation.approx_eq(&other.rotation, epsilon)
+    }
+}
+
+impl Mul<OrientedRect> for &Similarity {
+    type Output = OrientedRect;
+
+    fn mul(self, mut rhs: OrientedRect) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for OrientedRect {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl From<Rect> for OrientedRect {
+    fn from(value: Rect) -> Self {
+        Self::new(value.center(), value.half_extents(), 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{
+            scalar::consts::{FRAC_PI_2, FRAC_PI_4, SQRT_2},
+            vector,
+        },
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    // A 2 by 2 square turned into a diamond around (1, 1)
+    fn diamond() -> OrientedRect {
+        OrientedRect::new(Point::new(1.0, 1.0), vector!(1.0, 1.0), FRAC_PI_4)
+    }
+
+    #[test]
+    fn corners() {
+        let corners = diamond().corners();
+
+        assert_approx_eq!(
+            corners[0],
+            Point::new(1.0, 1.0 - SQRT_2),
+            2.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            corners[1],
+            Point::new(1.0 + SQRT_2, 1.0),
+            2.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(corners[2], Point::new(1.0, 1.0 + SQRT_2));
+        assert_approx_eq!(
+            corners[3],
+            Point::new(1.0 - SQRT_2, 1.0),
+            2.0 * Scalar::EPSILON
+        );
+        assert!(diamond().outline().is_closed());
+    }
+
+    #[test]
+    fn contains() {
+        let diamond = diamond();
+
+        assert!(diamond.contains(Point::new(1.0, 2.3)));
+        assert!(!diamond.contains(Point::new(2.0, 2.0)));
+        assert!(!diamond.contains(Point::new(-0.5, 1.0)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let diamond = diamond();
+
+        assert_approx_eq!(diamond.signed_distance(Point::new(1.0, 1.0)), -1.0);
+        assert_approx_eq!(
+            diamond.signed_distance(Point::new(3.0, 3.0)),
+            2.0 * SQRT_2 - 1.0,
+            4.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            diamond.signed_distance(Point::new(1.0, 4.0)),
+            3.0 - SQRT_2,
+            2.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn closest_point() {
+        let diamond = diamond();
+
+        assert_approx_eq!(
+            diamond.closest_point(Point::new(3.0, 3.0)),
+            Point::new(1.0 + FRAC_PI_4.cos(), 1.0 + FRAC_PI_4.sin())
+        );
+        assert_approx_eq!(
+            diamond.closest_point(Point::new(1.0, 4.0)),
+            Point::new(1.0, 1.0 + SQRT_2)
+        );
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            diamond().bounding_box(),
+            Rect::new(
+                Point::new(1.0 - SQRT_2, 1.0 - SQRT_2),
+                Point::new(1.0 + SQRT_2, 1.0 + SQRT_2)
+            ),
+            2.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn cast_ray() {
+        let diamond = diamond();
+
+        let ray = Ray::new(Point::new(-3.0, 1.0), vector!(2.0, 0.0));
+        assert_approx_eq!(diamond.cast_ray(&ray).unwrap(), (4.0 - SQRT_2) / 2.0);
+
+        let inside = Ray::new(Point::new(1.0, 1.0), Vector::Y);
+        assert_approx_eq!(
+            diamond.cast_ray(&inside).unwrap(),
+            SQRT_2,
+            2.0 * Scalar::EPSILON
+        );
+
+        let miss = Ray::new(Point::new(-3.0, 3.0), Vector::X);
+        assert_eq!(diamond.cast_ray(&miss), None);
+    }
+
+    #[test]
+    fn transform() {
+        let mut rect = diamond();
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let point = Point::new(3.0, 3.0);
+        let distance = rect.signed_distance(point);
+
+        rect *= &t;
+        assert_approx_eq!(
+            rect,
+            OrientedRect::new(Point::new(-1.0, 2.0), vector!(2.0, 2.0), 3.0 * FRAC_PI_4),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(rect.signed_distance(&t * point), 2.0 * distance);
+    }
+
+    #[test]
+    fn mirror() {
+        let rect = OrientedRect::new(Point::new(1.0, 1.0), vector!(2.0, 1.0), 0.3);
+        let t = Similarity::from_reflection(0.2);
+        let mirrored = &t * rect.clone();
+
+        for point in [
+            Point::new(3.0, 3.0),
+            Point::new(1.5, 1.0),
+            Point::new(-2.0, 0.5),
+        ] {
+            assert_approx_eq!(
+                mirrored.signed_distance(&t * point),
+                rect.signed_distance(point),
+                4.0 * Scalar::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn from_rect() {
+        let rect = Rect::new(Point::new(1.0, 1.0), Point::new(5.0, 3.0));
+        let oriented = OrientedRect::from(rect.clone());
+
+        for point in [
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.5),
+            Point::new(7.0, 2.0),
+        ] {
+            assert_approx_eq!(oriented.signed_distance(point), rect.signed_distance(point));
+        }
+    }
+
+    #[bench]
+    fn signed_distance_bench(b: &mut Bencher) {
+        let rect = diamond();
+        let point = Point::new(3.0, 3.0);
+
+        b.iter(|| black_box(rect.signed_distance(point)));
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    iter::{self, Peekable},
+    ops::{Mul, MulAssign},
+    slice,
+};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{Arc, Chain, Point, Rect, Segment, SignedDistance};
+
+// A chain whose edges may be circular arcs. Each edge carries a bulge as in `Arc::from_bulge`,
+// with zero meaning a straight line. Bulges survive similarities unchanged, except that
+// mirrors flip their sign.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    pub vertices: Vec<Point>,
+    pub bulges: Vec<Scalar>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathEdge {
+    Line(Segment),
+    Arc(Arc),
+}
+
+impl Path {
+    pub fn new(vertices: Vec<Point>, bulges: Vec<Scalar>) -> Self {
+        assert_eq!(
+            bulges.len(),
+            vertices.len().saturating_sub(1),
+            "A path needs one bulge per edge"
+        );
+        Self { vertices, bulges }
+    }
+
+    pub fn edges(&self) -> Edges<'_> {
+        Edges {
+            vertices: self.vertices.iter().peekable(),
+            bulges: self.bulges.iter(),
+        }
+    }
+
+    pub fn num_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.bulges.len()
+    }
+
+    pub fn length(&self) -> Scalar {
+        self.edges().map(|e| e.length()).sum()
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        self.edges()
+            .map(|e| e.distance_to_point(point))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let first = Rect::from_points(self.vertices.first().copied())?;
+        Some(
+            self.edges()
+                .fold(first, |rect, e| rect.union(&e.bounding_box())),
+        )
+    }
+
+    // Plain chain through every vertex whose arcs stay within `tolerance` of the path
+    pub fn flatten(&self, tolerance: Scalar) -> Chain {
+        let mut points: Vec<Point> = self.vertices.first().copied().into_iter().collect();
+        for (edge, &end) in iter::zip(self.edges(), self.vertices.iter().skip(1)) {
+            if let PathEdge::Arc(arc) = edge {
+                let flattened = arc.flatten(tolerance);
+                let interior = flattened.num_vertices() - 2;
+                points.extend(flattened.vertices().skip(1).take(interior));
+            }
+            // Land exactly on the vertex rather than on the evaluated arc end
+            points.push(end);
+        }
+        Chain::new(points)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.vertices.iter_mut().for_each(|v| *v *= t);
+        if t.reflects() {
+            self.bulges.iter_mut().for_each(|b| *b = -*b);
+        }
+    }
+}
+
+impl PathEdge {
+    pub fn start_point(&self) -> Point {
+        match self {
+            Self::Line(segment) => segment.start,
+            Self::Arc(arc) => arc.start_point(),
+        }
+    }
+
+    pub fn end_point(&self) -> Point {
+        match self {
+            Self::Line(segment) => segment.end,
+            Self::Arc(arc) => arc.end_point(),
+        }
+    }
+
+    pub fn length(&self) -> Scalar {
+        match self {
+            Self::Line(segment) => segment.length(),
+            Self::Arc(arc) => arc.length(),
+        }
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        match self {
+            Self::Line(segment) => segment.distance_to_point(point),
+            Self::Arc(arc) => arc.distance_to_point(point),
+        }
+    }
+
+    pub fn bounding_box(&self) -> Rect {
+        match self {
+            Self::Line(segment) => Rect::from_points([segment.start, segment.end]).unwrap(),
+            Self::Arc(arc) => arc.bounding_box(),
+        }
+    }
+}
+
+impl SignedDistance for Path {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for Path {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        if self.num_vertices() != other.num_vertices() {
+            return false;
+        }
+        for (v1, v2) in iter::zip(&self.vertices, &other.vertices) {
+            if v1.approx_ne(v2, epsilon) {
+                return false;
+            }
+        }
+        for (b1, b2) in iter::zip(&self.bulges, &other.bulges) {
+            if b1.approx_ne(b2, epsilon) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Mul<Path> for &Similarity {
+    type Output = Path;
+
+    fn mul(self, mut rhs: Path) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Path {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl From<Chain> for Path {
+    fn from(value: Chain) -> Self {
+        let bulges = vec![0.0; value.num_segments()];
+        Self::new(value.vertices, bulges)
+    }
+}
+
+pub struct Edges<'a> {
+    vertices: Peekable<slice::Iter<'a, Point>>,
+    bulges: slice::Iter<'a, Scalar>,
+}
+
+impl<'a> Iterator for Edges<'a> {
+    type Item = PathEdge;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.vertices.next()?;
+        let end = **self.vertices.peek()?;
+        let bulge = *self.bulges.next()?;
+        Some(match Arc::from_bulge(start, end, bulge) {
+            Some(arc) => PathEdge::Arc(arc),
+            None => PathEdge::Line(Segment::new(start, end)),
+        })
+    }
+}
+
+impl<'a> ExactSizeIterator for Edges<'a> {
+    fn len(&self) -> usize {
+        self.bulges.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::PI, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    // Slot outline: two straight sides joined by semicircular ends
+    fn slot() -> Path {
+        Path::new(
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 2.0),
+                Point::new(0.0, 2.0),
+                Point::new(0.0, 0.0),
+            ],
+            vec![0.0, 1.0, 0.0, 1.0],
+        )
+    }
+
+    #[test]
+    fn edges() {
+        let path = slot();
+        let mut edges = path.edges();
+
+        assert_eq!(
+            edges.next(),
+            Some(PathEdge::Line(Segment::new(
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0)
+            )))
+        );
+        let Some(PathEdge::Arc(arc)) = edges.next() else {
+            panic!("Expected an arc edge");
+        };
+        assert_approx_eq!(arc.center, Point::new(4.0, 1.0));
+        assert_approx_eq!(arc.radius, 1.0);
+        assert_eq!(edges.len(), 2);
+    }
+
+    #[test]
+    fn length() {
+        assert_approx_eq!(slot().length(), 8.0 + 2.0 * PI);
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let path = slot();
+
+        assert_approx_eq!(path.distance_to_point(Point::new(7.0, 1.0)), 2.0);
+        assert_approx_eq!(path.distance_to_point(Point::new(2.0, 1.5)), 0.5);
+        assert_approx_eq!(path.distance_to_point(Point::new(-2.0, 1.0)), 1.0);
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert_approx_eq!(
+            slot().bounding_box().unwrap(),
+            Rect::new(Point::new(-1.0, 0.0), Point::new(5.0, 2.0))
+        );
+        assert_eq!(Path::new(Vec::new(), Vec::new()).bounding_box(), None);
+    }
+
+    #[test]
+    fn flatten() {
+        let path = slot();
+        let tolerance = 0.001;
+        let chain = path.flatten(tolerance);
+
+        assert_eq!(chain.vertices[0], Point::new(0.0, 0.0));
+        assert_eq!(
+            chain.vertices[chain.num_vertices() - 1],
+            Point::new(0.0, 0.0)
+        );
+        assert!(chain.vertices.contains(&Point::new(4.0, 2.0)));
+        for segment in chain.segments() {
+            assert!(path.distance_to_point(segment.point_at(0.5)) <= tolerance);
+        }
+        assert!(chain.length() <= path.length());
+        assert!(chain.length() > path.length() - 0.01);
+    }
+
+    #[test]
+    fn from_chain() {
+        let chain = Chain::new(vec![
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 3.0),
+        ]);
+        let path = Path::from(chain.clone());
+
+        assert_approx_eq!(path.length(), chain.length());
+        assert_approx_eq!(path.flatten(0.1), chain);
+    }
+
+    #[test]
+    fn transform() {
+        let mut path = slot();
+        let t = Similarity::from_parts(2.0, PI / 2.0, vector!(1.0, 0.0));
+        let point = Point::new(7.0, 1.0);
+        let distance = path.distance_to_point(point);
+
+        path *= &t;
+        assert_eq!(path.bulges, slot().bulges);
+        assert_approx_eq!(
+            path.vertices[1],
+            Point::new(1.0, 8.0),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(path.distance_to_point(&t * point), 2.0 * distance);
+        assert_approx_eq!(path.length(), 2.0 * slot().length());
+    }
+
+    #[test]
+    fn mirror() {
+        let path = slot();
+        let t = Similarity::from_reflection(0.0);
+        let mirrored = &t * path.clone();
+
+        assert_eq!(mirrored.bulges, vec![0.0, -1.0, 0.0, -1.0]);
+        assert_approx_eq!(mirrored.distance_to_point(Point::new(5.0, -1.0)), 0.0);
+        assert_approx_eq!(
+            mirrored.flatten(0.01).signed_area(),
+            -path.flatten(0.01).signed_area()
+        );
+    }
+}
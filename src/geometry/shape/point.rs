@@ -1,10 +1,15 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::{
-    geometry::{transform::Similarity, vector, Scalar, Vector},
+    geometry::{
+        transform::{Affine, Homography, Isometry, Similarity},
+        vector, Scalar, Vector,
+    },
     utils::approx::ApproxEq,
 };
 
+use super::SignedDistance;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point(Vector);
 
@@ -54,6 +59,12 @@ impl Default for Point {
     }
 }
 
+impl SignedDistance for Point {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance(point)
+    }
+}
+
 impl ApproxEq for Point {
     type Epsilon = <Vector as ApproxEq>::Epsilon;
 
@@ -125,6 +136,31 @@ impl MulAssign<&Similarity> for Point {
     }
 }
 
+impl Mul<Point> for &Isometry {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        Point(self.apply(rhs.0))
+    }
+}
+
+impl Mul<Point> for &Affine {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        Point(self.apply(rhs.0))
+    }
+}
+
+// None for points sent to infinity
+impl Mul<Point> for &Homography {
+    type Output = Option<Point>;
+
+    fn mul(self, rhs: Point) -> Self::Output {
+        self.apply(rhs.0).map(Point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -182,6 +218,23 @@ mod tests {
         assert_approx_eq!(p, Point::ORIGIN);
     }
 
+    #[test]
+    fn affine_transform() {
+        let affine = Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+        assert_approx_eq!(&affine * Point::new(1.0, 2.0), Point::new(7.0, -1.0));
+    }
+
+    #[test]
+    fn homography_transform() {
+        let perspective = Homography::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.5, 1.0]]);
+
+        assert_approx_eq!(
+            (&perspective * Point::new(2.0, 2.0)).unwrap(),
+            Point::new(1.0, 1.0)
+        );
+        assert_eq!(&perspective * Point::new(1.0, -2.0), None);
+    }
+
     #[test]
     fn distance() {
         let p1 = Point::new(1.0, 3.0);
@@ -0,0 +1,345 @@
+use std::{
+    iter,
+    ops::{Mul, MulAssign},
+};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{chain_intersections, Chain, Point, Rect, SignedDistance};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolygonError {
+    OpenOuter,
+    OpenHole(usize),
+    // Rings are given by index, with the outer ring first and the holes after it
+    Crossing { point: Point, rings: (usize, usize) },
+    HoleOutside(usize),
+    NestedHoles(usize, usize),
+}
+
+// An outer ring with holes cut out of it. All rings are closed chains.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolygonWithHoles {
+    pub outer: Chain,
+    pub holes: Vec<Chain>,
+}
+
+impl PolygonWithHoles {
+    pub const fn new(outer: Chain, holes: Vec<Chain>) -> Self {
+        Self { outer, holes }
+    }
+
+    // The outer ring first, then the holes
+    pub fn rings(&self) -> impl Iterator<Item = &Chain> {
+        iter::once(&self.outer).chain(&self.holes)
+    }
+
+    // Winding numbers of all rings summed, so it depends on how they are oriented
+    pub fn winding_number(&self, point: Point) -> i32 {
+        self.rings().map(|r| r.winding_number(point)).sum()
+    }
+
+    // Boundary points count as inside under either rule
+    pub fn contains(&self, point: Point, rule: FillRule) -> bool {
+        let inside = match rule {
+            FillRule::EvenOdd => {
+                self.rings()
+                    .map(|r| r.crossing_number(point))
+                    .sum::<usize>()
+                    % 2
+                    == 1
+            }
+            FillRule::NonZero => self.winding_number(point) != 0,
+        };
+        inside || self.boundary_distance(point) == 0.0
+    }
+
+    // Negative inside, using the even-odd rule
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        let distance = self.boundary_distance(point);
+        if self.contains(point, FillRule::EvenOdd) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    pub fn area(&self) -> Scalar {
+        self.outer.signed_area().abs()
+            - self
+                .holes
+                .iter()
+                .map(|h| h.signed_area().abs())
+                .sum::<Scalar>()
+    }
+
+    pub fn perimeter(&self) -> Scalar {
+        self.rings().map(|r| r.length()).sum()
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect> {
+        Rect::from_points(self.outer.vertices())
+    }
+
+    // Turns the outer ring counterclockwise and the holes clockwise, so that both fill
+    // rules agree
+    pub fn orient(&mut self) {
+        if self.outer.signed_area() < 0.0 {
+            self.outer.vertices.reverse();
+        }
+        for hole in &mut self.holes {
+            if hole.signed_area() > 0.0 {
+                hole.vertices.reverse();
+            }
+        }
+    }
+
+    // Checks that all rings are closed, no two rings touch or cross, and every hole lies
+    // inside the outer ring and outside the other holes
+    pub fn validate(&self) -> Result<(), PolygonError> {
+        if !self.outer.is_closed() {
+            return Err(PolygonError::OpenOuter);
+        }
+        if let Some(i) = self.holes.iter().position(|h| !h.is_closed()) {
+            return Err(PolygonError::OpenHole(i));
+        }
+
+        let rings: Vec<Chain> = self.rings().cloned().collect();
+        if let Some(intersection) = chain_intersections(&rings).first() {
+            return Err(PolygonError::Crossing {
+                point: intersection.point,
+                rings: (intersection.first.0, intersection.second.0),
+            });
+        }
+
+        // Without crossings, one vertex decides on which side a whole ring lies
+        for (i, hole) in self.holes.iter().enumerate() {
+            if self.outer.winding_number(hole.vertices[0]) == 0 {
+                return Err(PolygonError::HoleOutside(i));
+            }
+            for (j, other) in self.holes.iter().enumerate() {
+                if i != j && other.winding_number(hole.vertices[0]) != 0 {
+                    return Err(PolygonError::NestedHoles(j, i));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.outer *= t;
+        self.holes.iter_mut().for_each(|h| *h *= t);
+    }
+
+    fn boundary_distance(&self, point: Point) -> Scalar {
+        self.rings()
+            .map(|r| r.distance_to_point(point))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+}
+
+impl SignedDistance for PolygonWithHoles {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        PolygonWithHoles::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for PolygonWithHoles {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.holes.len() == other.holes.len()
+            && iter::zip(self.rings(), other.rings()).all(|(r1, r2)| r1.approx_eq(r2, epsilon))
+    }
+}
+
+impl Mul<PolygonWithHoles> for &Similarity {
+    type Output = PolygonWithHoles;
+
+    fn mul(self, mut rhs: PolygonWithHoles) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for PolygonWithHoles {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl From<Chain> for PolygonWithHoles {
+    fn from(value: Chain) -> Self {
+        Self::new(value, Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn square(min: (Scalar, Scalar), size: Scalar) -> Chain {
+        Rect::new(
+            Point::new(min.0, min.1),
+            Point::new(min.0 + size, min.1 + size),
+        )
+        .outline()
+    }
+
+    // A 10 by 10 square with two 2 by 2 holes, one of them wound the same way as the outer ring
+    fn frame() -> PolygonWithHoles {
+        let reversed: Chain = square((2.0, 2.0), 2.0).vertices().rev().collect();
+        PolygonWithHoles::new(
+            square((0.0, 0.0), 10.0),
+            vec![reversed, square((6.0, 6.0), 2.0)],
+        )
+    }
+
+    #[test]
+    fn contains() {
+        let frame = frame();
+
+        assert!(frame.contains(Point::new(1.0, 1.0), FillRule::EvenOdd));
+        assert!(frame.contains(Point::new(1.0, 1.0), FillRule::NonZero));
+        assert!(!frame.contains(Point::new(11.0, 1.0), FillRule::EvenOdd));
+
+        // The clockwise hole is a hole under both rules
+        assert!(!frame.contains(Point::new(3.0, 3.0), FillRule::EvenOdd));
+        assert!(!frame.contains(Point::new(3.0, 3.0), FillRule::NonZero));
+
+        // The counterclockwise hole only is under even-odd
+        assert!(!frame.contains(Point::new(7.0, 7.0), FillRule::EvenOdd));
+        assert!(frame.contains(Point::new(7.0, 7.0), FillRule::NonZero));
+        assert_eq!(frame.winding_number(Point::new(7.0, 7.0)), 2);
+
+        assert!(frame.contains(Point::new(2.0, 3.0), FillRule::EvenOdd));
+        assert!(frame.contains(Point::new(10.0, 5.0), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn orient() {
+        let mut frame = frame();
+        frame.orient();
+
+        assert!(frame.outer.signed_area() > 0.0);
+        assert!(frame.holes.iter().all(|h| h.signed_area() < 0.0));
+        assert!(!frame.contains(Point::new(7.0, 7.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let frame = frame();
+
+        assert_approx_eq!(frame.signed_distance(Point::new(1.0, 5.0)), -1.0);
+        assert_approx_eq!(frame.signed_distance(Point::new(3.0, 2.5)), 0.5);
+        assert_approx_eq!(frame.signed_distance(Point::new(5.0, 3.0)), -1.0);
+        assert_approx_eq!(frame.signed_distance(Point::new(13.0, 14.0)), 5.0);
+    }
+
+    #[test]
+    fn measures() {
+        let frame = frame();
+
+        assert_approx_eq!(frame.area(), 92.0);
+        assert_approx_eq!(frame.perimeter(), 56.0);
+        assert_approx_eq!(
+            frame.bounding_box().unwrap(),
+            Rect::new(Point::ORIGIN, Point::new(10.0, 10.0))
+        );
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(frame().validate(), Ok(()));
+
+        let open = PolygonWithHoles::from(Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(1.0, 1.0),
+        ]));
+        assert_eq!(open.validate(), Err(PolygonError::OpenOuter));
+
+        let mut outside = frame();
+        outside.holes.push(square((20.0, 0.0), 1.0));
+        assert_eq!(outside.validate(), Err(PolygonError::HoleOutside(2)));
+
+        let mut nested = frame();
+        nested.holes.push(square((2.5, 2.5), 1.0));
+        assert_eq!(nested.validate(), Err(PolygonError::NestedHoles(0, 2)));
+
+        let mut crossing = frame();
+        crossing.holes.push(square((9.0, 4.0), 2.0));
+        assert!(matches!(
+            crossing.validate(),
+            Err(PolygonError::Crossing { rings: (0, 3), .. }
+                | PolygonError::Crossing { rings: (3, 0), .. })
+        ));
+    }
+
+    #[test]
+    fn transform() {
+        let mut frame = frame();
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let point = Point::new(3.0, 2.5);
+        let distance = frame.signed_distance(point);
+
+        frame *= &t;
+        assert_approx_eq!(frame.area(), 368.0);
+        assert_approx_eq!(
+            frame.signed_distance(&t * point),
+            2.0 * distance,
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn mirror() {
+        let frame = frame();
+        let t = Similarity::from_reflection(0.0);
+        let mirrored = &t * frame.clone();
+
+        assert!(mirrored.outer.signed_area() < 0.0);
+        assert_approx_eq!(mirrored.area(), frame.area());
+        for point in [
+            Point::new(1.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(7.0, 7.0),
+        ] {
+            for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+                assert_eq!(
+                    mirrored.contains(&t * point, rule),
+                    frame.contains(point, rule)
+                );
+            }
+        }
+
+        let mut oriented = mirrored;
+        oriented.orient();
+        assert!(oriented.outer.signed_area() > 0.0);
+        assert!(oriented.holes.iter().all(|h| h.signed_area() < 0.0));
+    }
+
+    #[bench]
+    fn signed_distance_bench(b: &mut Bencher) {
+        let frame = frame();
+        let point = Point::new(5.0, 5.0);
+
+        b.iter(|| black_box(frame.signed_distance(point)));
+    }
+}
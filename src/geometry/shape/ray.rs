@@ -0,0 +1,146 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar, Vector},
+    utils::approx::ApproxEq,
+};
+
+use super::{Line, Point, Segment, SignedDistance};
+
+// Casting a ray onto a shape gives the parameter of the first point at or after the origin
+// where the ray crosses the boundary of the shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ray {
+    pub origin: Point,
+    pub direction: Vector,
+}
+
+impl Ray {
+    pub const fn new(origin: Point, direction: Vector) -> Self {
+        Self { origin, direction }
+    }
+
+    pub fn point_at(&self, t: Scalar) -> Point {
+        self.origin + self.direction * t
+    }
+
+    pub fn line(&self) -> Line {
+        Line::new(self.origin, self.direction)
+    }
+
+    pub fn project(&self, point: Point) -> Scalar {
+        self.line().project(point).max(0.0)
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.point_at(self.project(point))
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        point.distance(self.closest_point(point))
+    }
+
+    // Parameter along the ray where it crosses the line
+    pub fn line_intersection(&self, line: &Line) -> Option<Scalar> {
+        let (t, _) = self.line().intersection_parameters(line)?;
+        (t >= 0.0).then_some(t)
+    }
+
+    // Parameter along the ray where it crosses the segment
+    pub fn segment_intersection(&self, segment: &Segment) -> Option<Scalar> {
+        let (t, u) = self
+            .line()
+            .intersection_parameters(&Line::from(segment.clone()))?;
+        (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.origin *= t;
+        self.direction = t.apply_linear(self.direction);
+    }
+}
+
+impl SignedDistance for Ray {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for Ray {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.origin.approx_eq(&other.origin, epsilon)
+            && self.direction.approx_eq(&other.direction, epsilon)
+    }
+}
+
+impl Mul<Ray> for &Similarity {
+    type Output = Ray;
+
+    fn mul(self, mut rhs: Ray) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Ray {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    #[test]
+    fn point_at() {
+        let ray = Ray::new(Point::new(1.0, 1.0), vector!(2.0, -1.0));
+        assert_approx_eq!(ray.point_at(1.5), Point::new(4.0, -0.5));
+    }
+
+    #[test]
+    fn closest_point() {
+        let ray = Ray::new(Point::new(1.0, 1.0), vector!(2.0, 0.0));
+
+        assert_approx_eq!(ray.project(Point::new(4.0, 5.0)), 1.5);
+        assert_approx_eq!(ray.project(Point::new(-3.0, 0.0)), 0.0);
+        assert_approx_eq!(
+            ray.closest_point(Point::new(4.0, 5.0)),
+            Point::new(4.0, 1.0)
+        );
+        assert_approx_eq!(ray.distance_to_point(Point::new(-2.0, 5.0)), 5.0);
+    }
+
+    #[test]
+    fn intersections() {
+        let ray = Ray::new(Point::new(1.0, 1.0), vector!(2.0, 0.0));
+
+        let ahead = Segment::new(Point::new(3.0, 0.0), Point::new(5.0, 4.0));
+        assert_approx_eq!(ray.segment_intersection(&ahead).unwrap(), 1.25);
+
+        let behind = Segment::new(Point::new(-1.0, 0.0), Point::new(-1.0, 2.0));
+        assert_eq!(ray.segment_intersection(&behind), None);
+        assert_eq!(ray.line_intersection(&Line::from(behind)), None);
+
+        let line = Line::new(Point::new(2.0, 0.0), Vector::Y);
+        assert_approx_eq!(ray.line_intersection(&line).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn transform() {
+        let mut ray = Ray::new(Point::new(1.0, 2.0), Vector::X);
+        ray *= &Similarity::from_parts(2.0, scalar::consts::TAU / 4.0, vector!(2.0, -1.0));
+        assert_approx_eq!(
+            ray,
+            Ray::new(Point::new(-2.0, 1.0), vector!(0.0, 2.0)),
+            8.0 * Scalar::EPSILON
+        );
+    }
+}
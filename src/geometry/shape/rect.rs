@@ -0,0 +1,330 @@
+use std::ops::Mul;
+
+use crate::{
+    geometry::{transform::Similarity, vector, Scalar, Vector},
+    utils::approx::ApproxEq,
+};
+
+use super::{Chain, OrientedRect, Point, Ray, SignedDistance};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub const fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    pub fn from_center(center: Point, half_width: Scalar, half_height: Scalar) -> Self {
+        Self::new(
+            Point::new(center.x() - half_width, center.y() - half_height),
+            Point::new(center.x() + half_width, center.y() + half_height),
+        )
+    }
+
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, p| {
+            Self::new(
+                Point::new(rect.min.x().min(p.x()), rect.min.y().min(p.y())),
+                Point::new(rect.max.x().max(p.x()), rect.max.y().max(p.y())),
+            )
+        }))
+    }
+
+    pub fn width(&self) -> Scalar {
+        self.max.x() - self.min.x()
+    }
+
+    pub fn height(&self) -> Scalar {
+        self.max.y() - self.min.y()
+    }
+
+    pub fn center(&self) -> Point {
+        self.min + (self.max - self.min) / 2.0
+    }
+
+    pub fn half_extents(&self) -> Vector {
+        (self.max - self.min) / 2.0
+    }
+
+    // Counterclockwise, starting at `min`
+    pub fn corners(&self) -> [Point; 4] {
+        [
+            self.min,
+            Point::new(self.max.x(), self.min.y()),
+            self.max,
+            Point::new(self.min.x(), self.max.y()),
+        ]
+    }
+
+    // Closed chain around the boundary
+    pub fn outline(&self) -> Chain {
+        let corners = self.corners();
+        corners.iter().chain(&corners[..1]).copied().collect()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x()..=self.max.x()).contains(&point.x())
+            && (self.min.y()..=self.max.y()).contains(&point.y())
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        let clamped = Point::new(
+            point.x().clamp(self.min.x(), self.max.x()),
+            point.y().clamp(self.min.y(), self.max.y()),
+        );
+        if clamped != point {
+            return clamped;
+        }
+
+        // Inside, so move to the nearest edge
+        let to_min = point - self.min;
+        let to_max = self.max - point;
+        let nearest = to_min.x.min(to_min.y).min(to_max.x).min(to_max.y);
+        if nearest == to_min.x {
+            Point::new(self.min.x(), point.y())
+        } else if nearest == to_max.x {
+            Point::new(self.max.x(), point.y())
+        } else if nearest == to_min.y {
+            Point::new(point.x(), self.min.y())
+        } else {
+            Point::new(point.x(), self.max.y())
+        }
+    }
+
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        let offset = point - self.center();
+        let half_extents = self.half_extents();
+        let q = vector!(
+            offset.x.abs() - half_extents.x,
+            offset.y.abs() - half_extents.y
+        );
+        let outside = vector!(q.x.max(0.0), q.y.max(0.0)).norm();
+        let inside = q.x.max(q.y).min(0.0);
+        outside + inside
+    }
+
+    pub fn cast_ray(&self, ray: &Ray) -> Option<Scalar> {
+        let mut enter = Scalar::NEG_INFINITY;
+        let mut exit = Scalar::INFINITY;
+        for (origin, direction, min, max) in [
+            (ray.origin.x(), ray.direction.x, self.min.x(), self.max.x()),
+            (ray.origin.y(), ray.direction.y, self.min.y(), self.max.y()),
+        ] {
+            if direction == 0.0 {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let t1 = (min - origin) / direction;
+            let t2 = (max - origin) / direction;
+            enter = enter.max(t1.min(t2));
+            exit = exit.min(t1.max(t2));
+        }
+
+        if enter > exit || exit < 0.0 || exit == Scalar::INFINITY {
+            return None;
+        }
+        Some(if enter >= 0.0 { enter } else { exit })
+    }
+
+    // Grown by the margin on every side
+    pub fn expand(&self, margin: Scalar) -> Rect {
+        Self::new(
+            self.min - vector!(margin, margin),
+            self.max + vector!(margin, margin),
+        )
+    }
+
+    pub fn union(&self, other: &Rect) -> Rect {
+        Self::new(
+            Point::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+            ),
+            Point::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+            ),
+        )
+    }
+}
+
+impl ApproxEq for Rect {
+    type Epsilon = <Point as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.min.approx_eq(&other.min, epsilon) && self.max.approx_eq(&other.max, epsilon)
+    }
+}
+
+impl SignedDistance for Rect {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Rect::signed_distance(self, point)
+    }
+}
+
+// Rotations do not keep a rectangle axis-aligned
+impl Mul<Rect> for &Similarity {
+    type Output = OrientedRect;
+
+    fn mul(self, rhs: Rect) -> Self::Output {
+        self * OrientedRect::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::scalar::consts::FRAC_PI_2, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    #[test]
+    fn from_center() {
+        let rect = Rect::from_center(Point::new(1.0, 2.0), 3.0, 1.0);
+
+        assert_approx_eq!(rect, Rect::new(Point::new(-2.0, 1.0), Point::new(4.0, 3.0)));
+        assert_approx_eq!(rect.width(), 6.0);
+        assert_approx_eq!(rect.height(), 2.0);
+    }
+
+    #[test]
+    fn from_points() {
+        let rect = Rect::from_points([
+            Point::new(1.0, 2.0),
+            Point::new(-1.0, 3.0),
+            Point::new(0.0, -2.0),
+        ])
+        .unwrap();
+
+        assert_approx_eq!(
+            rect,
+            Rect::new(Point::new(-1.0, -2.0), Point::new(1.0, 3.0))
+        );
+        assert_eq!(Rect::from_points([]), None);
+    }
+
+    #[test]
+    fn union() {
+        let r1 = Rect::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0));
+        let r2 = Rect::new(Point::new(1.0, -1.0), Point::new(3.0, 0.5));
+
+        assert_approx_eq!(
+            r1.union(&r2),
+            Rect::new(Point::new(0.0, -1.0), Point::new(3.0, 1.0))
+        );
+    }
+
+    #[test]
+    fn expand() {
+        let rect = Rect::new(Point::new(0.0, 0.0), Point::new(2.0, 1.0));
+
+        assert_approx_eq!(
+            rect.expand(0.5),
+            Rect::new(Point::new(-0.5, -0.5), Point::new(2.5, 1.5))
+        );
+    }
+
+    fn rect() -> Rect {
+        Rect::new(Point::new(1.0, 1.0), Point::new(5.0, 3.0))
+    }
+
+    #[test]
+    fn corners() {
+        let rect = rect();
+
+        assert_eq!(
+            rect.corners(),
+            [
+                Point::new(1.0, 1.0),
+                Point::new(5.0, 1.0),
+                Point::new(5.0, 3.0),
+                Point::new(1.0, 3.0),
+            ]
+        );
+        assert!(rect.outline().is_closed());
+        assert_approx_eq!(rect.center(), Point::new(3.0, 2.0));
+        assert_approx_eq!(rect.half_extents(), vector!(2.0, 1.0));
+    }
+
+    #[test]
+    fn contains() {
+        let rect = rect();
+
+        assert!(rect.contains(Point::new(2.0, 2.0)));
+        assert!(rect.contains(Point::new(5.0, 3.0)));
+        assert!(!rect.contains(Point::new(0.0, 2.0)));
+        assert!(!rect.contains(Point::new(2.0, 3.5)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let rect = rect();
+
+        assert_approx_eq!(rect.signed_distance(Point::new(8.0, 7.0)), 5.0);
+        assert_approx_eq!(rect.signed_distance(Point::new(3.0, 0.0)), 1.0);
+        assert_approx_eq!(rect.signed_distance(Point::new(3.0, 2.0)), -1.0);
+        assert_approx_eq!(rect.signed_distance(Point::new(4.5, 2.0)), -0.5);
+        assert_approx_eq!(rect.signed_distance(Point::new(5.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn closest_point() {
+        let rect = rect();
+
+        assert_approx_eq!(
+            rect.closest_point(Point::new(8.0, 7.0)),
+            Point::new(5.0, 3.0)
+        );
+        assert_approx_eq!(
+            rect.closest_point(Point::new(4.5, 2.0)),
+            Point::new(5.0, 2.0)
+        );
+        assert_approx_eq!(
+            rect.closest_point(Point::new(2.0, 1.2)),
+            Point::new(2.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn cast_ray() {
+        let rect = rect();
+
+        let hit = Ray::new(Point::new(-1.0, 2.0), vector!(2.0, 0.0));
+        assert_approx_eq!(rect.cast_ray(&hit).unwrap(), 1.0);
+
+        let inside = Ray::new(Point::new(2.0, 2.0), vector!(0.0, -1.0));
+        assert_approx_eq!(rect.cast_ray(&inside).unwrap(), 1.0);
+
+        let diagonal = Ray::new(Point::new(0.0, 0.0), vector!(1.0, 1.0));
+        assert_approx_eq!(rect.cast_ray(&diagonal).unwrap(), 1.0);
+
+        let parallel_miss = Ray::new(Point::new(0.0, 4.0), Vector::X);
+        assert_eq!(rect.cast_ray(&parallel_miss), None);
+
+        let behind = Ray::new(Point::new(6.0, 2.0), Vector::X);
+        assert_eq!(rect.cast_ray(&behind), None);
+
+        let stationary = Ray::new(Point::new(2.0, 2.0), Vector::ZERO);
+        assert_eq!(rect.cast_ray(&stationary), None);
+    }
+
+    #[test]
+    fn transform() {
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let oriented = &t * rect();
+
+        assert_approx_eq!(
+            oriented,
+            OrientedRect::new(Point::new(-3.0, 6.0), vector!(4.0, 2.0), FRAC_PI_2),
+            8.0 * Scalar::EPSILON
+        );
+    }
+}
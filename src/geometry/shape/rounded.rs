@@ -0,0 +1,225 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{Capsule, Circle, Point, Segment, SignedDistance};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rounded<S> {
+    pub shape: S,
+    pub radius: Scalar,
+}
+
+impl<S> Rounded<S> {
+    pub const fn new(shape: S, radius: Scalar) -> Self {
+        Self { shape, radius }
+    }
+}
+
+impl<S> Rounded<S>
+where
+    S: for<'a> MulAssign<&'a Similarity>,
+{
+    pub fn transform(&mut self, t: &Similarity) {
+        self.shape *= t;
+        self.radius *= t.scale();
+    }
+}
+
+impl<S: SignedDistance> SignedDistance for Rounded<S> {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.shape.signed_distance(point) - self.radius
+    }
+}
+
+impl<S> ApproxEq for Rounded<S>
+where
+    S: ApproxEq<Epsilon = <Scalar as ApproxEq>::Epsilon>,
+{
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.shape.approx_eq(&other.shape, epsilon) && self.radius.approx_eq(&other.radius, epsilon)
+    }
+}
+
+impl<S> Mul<Rounded<S>> for &Similarity
+where
+    S: for<'a> MulAssign<&'a Similarity>,
+{
+    type Output = Rounded<S>;
+
+    fn mul(self, mut rhs: Rounded<S>) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl<S> MulAssign<&Similarity> for Rounded<S>
+where
+    S: for<'a> MulAssign<&'a Similarity>,
+{
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+impl From<Circle> for Rounded<Point> {
+    fn from(value: Circle) -> Self {
+        Self::new(value.center, value.radius)
+    }
+}
+
+impl From<Rounded<Point>> for Circle {
+    fn from(value: Rounded<Point>) -> Self {
+        Self::new(value.shape, value.radius)
+    }
+}
+
+impl From<Capsule> for Rounded<Segment> {
+    fn from(value: Capsule) -> Self {
+        Self::new(value.segment, value.radius)
+    }
+}
+
+impl From<Rounded<Segment>> for Capsule {
+    fn from(value: Rounded<Segment>) -> Self {
+        Self::new(value.shape, value.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{
+            scalar,
+            shape::{Chain, Shape},
+            vector,
+        },
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    #[test]
+    fn rounded_point_is_circle() {
+        let rounded = Rounded::new(Point::new(1.0, 1.0), 1.0);
+        let circle = Circle::from(rounded.clone());
+        let point = Point::new(4.0, 5.0);
+
+        assert_approx_eq!(rounded.signed_distance(point), 4.0);
+        assert_approx_eq!(
+            rounded.signed_distance(point),
+            circle.signed_distance(point)
+        );
+    }
+
+    #[test]
+    fn rounded_segment_is_capsule() {
+        let rounded = Rounded::new(
+            Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0)),
+            1.0,
+        );
+        let capsule = Capsule::from(rounded.clone());
+
+        for point in [
+            Point::new(2.0, 3.0),
+            Point::new(7.0, 4.0),
+            Point::new(1.0, 0.5),
+        ] {
+            assert_approx_eq!(
+                rounded.signed_distance(point),
+                capsule.signed_distance(point)
+            );
+        }
+    }
+
+    #[test]
+    fn rounded_chain() {
+        let rounded = Rounded::new(
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 4.0),
+            ]),
+            0.5,
+        );
+
+        assert_approx_eq!(rounded.signed_distance(Point::new(2.0, 2.0)), 1.5);
+        assert_approx_eq!(rounded.signed_distance(Point::new(4.0, 2.0)), -0.5);
+    }
+
+    #[test]
+    fn nested() {
+        let rounded = Rounded::new(Rounded::new(Point::ORIGIN, 1.0), 2.0);
+        assert_approx_eq!(rounded.signed_distance(Point::new(5.0, 0.0)), 2.0);
+    }
+
+    #[test]
+    fn shape_distance() {
+        let rounded = Shape::from(Rounded::new(
+            Shape::from(Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0))),
+            1.0,
+        ));
+        let other = Shape::from(Rounded::new(Shape::from(Point::new(2.0, 4.0)), 0.5));
+
+        assert_approx_eq!(rounded.signed_distance(&other), 2.5);
+        assert_approx_eq!(other.signed_distance(&rounded), 2.5);
+    }
+
+    #[test]
+    fn shape_thick_polyline() {
+        let polyline = Shape::from(Rounded::new(
+            Shape::from(Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(4.0, 0.0),
+                Point::new(4.0, 4.0),
+            ])),
+            0.5,
+        ));
+        let circle = Shape::from(Circle::new(Point::new(2.0, 2.0), 0.5));
+        let capsule = Shape::from(Capsule::new(
+            Segment::new(Point::new(6.0, 1.0), Point::new(6.0, 3.0)),
+            1.0,
+        ));
+
+        assert_approx_eq!(polyline.signed_distance(&circle), 1.0);
+        assert_approx_eq!(circle.signed_distance(&polyline), 1.0);
+        assert_approx_eq!(polyline.signed_distance(&capsule), 0.5);
+
+        let moved = polyline * &Similarity::from_parts(2.0, 0.0, vector!(1.0, 0.0));
+        assert_approx_eq!(moved.signed_distance(&circle), 0.5);
+    }
+
+    #[test]
+    fn shape_crossing_thick_polyline() {
+        let chain = Shape::from(Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+        ]));
+        let polyline = Shape::from(Rounded::new(chain.clone(), 0.5));
+        let crossing = Segment::new(Point::new(2.0, -1.0), Point::new(2.0, 1.0));
+        let segment = Shape::from(crossing.clone());
+        let capsule = Shape::from(Capsule::new(crossing, 1.0));
+
+        assert_approx_eq!(segment.signed_distance(&chain), 0.0);
+        assert_approx_eq!(polyline.signed_distance(&segment), -0.5);
+        assert_approx_eq!(capsule.signed_distance(&polyline), -1.5);
+        assert_approx_eq!(chain.signed_distance(&polyline), -0.5);
+    }
+
+    #[test]
+    fn transform() {
+        let mut rounded = Rounded::new(Point::new(1.0, 2.0), 1.0);
+        rounded *= &Similarity::from_parts(2.0, scalar::consts::TAU / 4.0, vector!(2.0, -1.0));
+        assert_approx_eq!(
+            rounded,
+            Rounded::new(Point::new(-2.0, 1.0), 2.0),
+            8.0 * Scalar::EPSILON
+        );
+    }
+}
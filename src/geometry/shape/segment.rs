@@ -1,11 +1,14 @@
 use std::ops::{Mul, MulAssign};
 
 use crate::{
-    geometry::{transform::Similarity, Scalar, Vector},
+    geometry::{
+        transform::{Affine, Homography, Similarity},
+        Scalar, Vector,
+    },
     utils::approx::ApproxEq,
 };
 
-use super::Point;
+use super::{Point, SignedDistance};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Segment {
@@ -26,12 +29,72 @@ impl Segment {
         self.as_vector().norm()
     }
 
-    pub fn distance_to_point(&self, point: Point) -> Scalar {
+    pub fn point_at(&self, t: Scalar) -> Point {
+        self.start + t * self.as_vector()
+    }
+
+    pub fn project(&self, point: Point) -> Scalar {
         let segment_vec = self.as_vector();
-        let t = (point - self.start).dot(segment_vec) / segment_vec.norm_sq();
-        let t_clamped = t.clamp(0.0, 1.0);
-        let closest_point = self.start + t_clamped * segment_vec;
-        point.distance(closest_point)
+        let length_sq = segment_vec.norm_sq();
+        if length_sq == 0.0 {
+            return 0.0;
+        }
+
+        let t = (point - self.start).dot(segment_vec) / length_sq;
+        t.clamp(0.0, 1.0)
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        self.point_at(self.project(point))
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        point.distance(self.closest_point(point))
+    }
+
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let o1 = self.as_vector().perp_dot(other.start - self.start);
+        let o2 = self.as_vector().perp_dot(other.end - self.start);
+        let o3 = other.as_vector().perp_dot(self.start - other.start);
+        let o4 = other.as_vector().perp_dot(self.end - other.start);
+
+        if o1 * o2 < 0.0 && o3 * o4 < 0.0 {
+            return true;
+        }
+
+        (o1 == 0.0 && self.bounds_contain(other.start))
+            || (o2 == 0.0 && self.bounds_contain(other.end))
+            || (o3 == 0.0 && other.bounds_contain(self.start))
+            || (o4 == 0.0 && other.bounds_contain(self.end))
+    }
+
+    pub fn intersection(&self, other: &Segment) -> Option<Point> {
+        let d1 = self.as_vector();
+        let d2 = other.as_vector();
+        let offset = other.start - self.start;
+        let denominator = d1.perp_dot(d2);
+
+        if denominator != 0.0 {
+            let t = offset.perp_dot(d2) / denominator;
+            let u = offset.perp_dot(d1) / denominator;
+            return ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u))
+                .then(|| self.point_at(t));
+        }
+
+        if offset.perp_dot(d1) != 0.0 || offset.perp_dot(other.end - self.start) != 0.0 {
+            return None;
+        }
+
+        let length_sq = d1.norm_sq();
+        if length_sq == 0.0 {
+            return (other.distance_to_point(self.start) == 0.0).then_some(self.start);
+        }
+
+        let t0 = offset.dot(d1) / length_sq;
+        let t1 = (other.end - self.start).dot(d1) / length_sq;
+        let t_min = t0.min(t1).max(0.0);
+        let t_max = t0.max(t1).min(1.0);
+        (t_min <= t_max).then(|| self.point_at(t_min))
     }
 
     pub fn horizontal_ray_intersection_type(&self, ray_start: Point) -> i32 {
@@ -56,6 +119,19 @@ impl Segment {
         self.start *= t;
         self.end *= t;
     }
+
+    fn bounds_contain(&self, point: Point) -> bool {
+        point.x() >= self.start.x().min(self.end.x())
+            && point.x() <= self.start.x().max(self.end.x())
+            && point.y() >= self.start.y().min(self.end.y())
+            && point.y() <= self.start.y().max(self.end.y())
+    }
+}
+
+impl SignedDistance for Segment {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
 }
 
 impl ApproxEq for Segment {
@@ -81,6 +157,31 @@ impl MulAssign<&Similarity> for Segment {
     }
 }
 
+impl Mul<Segment> for &Affine {
+    type Output = Segment;
+
+    fn mul(self, rhs: Segment) -> Self::Output {
+        Segment::new(self * rhs.start, self * rhs.end)
+    }
+}
+
+// None when the segment touches the line sent to infinity, as its image is then unbounded
+impl Mul<Segment> for &Homography {
+    type Output = Option<Segment>;
+
+    fn mul(self, rhs: Segment) -> Self::Output {
+        let start = self.apply_homogeneous(rhs.start.as_vector().into());
+        let end = self.apply_homogeneous(rhs.end.as_vector().into());
+        if start.w * end.w <= 0.0 {
+            return None;
+        }
+        Some(Segment::new(
+            Point::from(start.to_vector()?),
+            Point::from(end.to_vector()?),
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use test::{black_box, Bencher};
@@ -110,6 +211,38 @@ mod tests {
         assert_approx_eq!(segment.length(), Scalar::sqrt(5.0));
     }
 
+    #[test]
+    fn point_at() {
+        let segment = Segment::new(Point::new(1.0, 2.0), Point::new(3.0, 6.0));
+
+        assert_approx_eq!(segment.point_at(0.0), Point::new(1.0, 2.0));
+        assert_approx_eq!(segment.point_at(0.5), Point::new(2.0, 4.0));
+        assert_approx_eq!(segment.point_at(1.0), Point::new(3.0, 6.0));
+    }
+
+    #[test]
+    fn project() {
+        let segment = Segment::new(Point::new(1.0, 2.0), Point::new(3.0, 2.0));
+
+        assert_approx_eq!(segment.project(Point::new(1.5, 5.0)), 0.25);
+        assert_approx_eq!(segment.project(Point::new(-1.0, 0.0)), 0.0);
+        assert_approx_eq!(segment.project(Point::new(4.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn closest_point() {
+        let segment = Segment::new(Point::new(1.0, 2.0), Point::new(3.0, 2.0));
+
+        assert_approx_eq!(
+            segment.closest_point(Point::new(1.5, 5.0)),
+            Point::new(1.5, 2.0)
+        );
+        assert_approx_eq!(
+            segment.closest_point(Point::new(4.0, 0.0)),
+            Point::new(3.0, 2.0)
+        );
+    }
+
     #[test]
     fn distance_to_point_along_line() {
         let p1 = Point::new(1.0, 2.0);
@@ -130,6 +263,91 @@ mod tests {
         assert_approx_eq!(segment.distance_to_point(q), 2.0);
     }
 
+    #[test]
+    fn distance_to_degenerate_segment() {
+        let p = Point::new(1.0, 2.0);
+        let segment = Segment::new(p, p);
+
+        let q = Point::new(4.0, 6.0);
+        assert_approx_eq!(segment.distance_to_point(q), 5.0);
+    }
+
+    #[test]
+    fn intersects_crossing() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+
+        assert!(s1.intersects(&s2));
+        assert!(s2.intersects(&s1));
+    }
+
+    #[test]
+    fn intersects_touching() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(1.0, 1.0), Point::new(2.0, 0.0));
+
+        assert!(s1.intersects(&s2));
+        assert!(s2.intersects(&s1));
+    }
+
+    #[test]
+    fn intersects_collinear() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        let s3 = Segment::new(Point::new(3.0, 3.0), Point::new(4.0, 4.0));
+
+        assert!(s1.intersects(&s2));
+        assert!(!s1.intersects(&s3));
+    }
+
+    #[test]
+    fn intersects_disjoint() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(2.0, 0.0), Point::new(3.0, 1.0));
+
+        assert!(!s1.intersects(&s2));
+        assert!(!s2.intersects(&s1));
+    }
+
+    #[test]
+    fn intersection_crossing() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0));
+
+        assert_approx_eq!(s1.intersection(&s2).unwrap(), Point::new(1.0, 1.0));
+        assert_approx_eq!(s2.intersection(&s1).unwrap(), Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(2.0, 0.0), Point::new(3.0, 1.0));
+        let s3 = Segment::new(Point::new(3.0, 0.0), Point::new(1.5, 1.0));
+
+        assert_eq!(s1.intersection(&s2), None);
+        assert_eq!(s1.intersection(&s3), None);
+    }
+
+    #[test]
+    fn intersection_collinear() {
+        let s1 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let s2 = Segment::new(Point::new(3.0, 3.0), Point::new(1.0, 1.0));
+        let s3 = Segment::new(Point::new(3.0, 3.0), Point::new(4.0, 4.0));
+
+        assert_approx_eq!(s1.intersection(&s2).unwrap(), Point::new(1.0, 1.0));
+        assert_approx_eq!(s2.intersection(&s1).unwrap(), Point::new(2.0, 2.0));
+        assert_eq!(s1.intersection(&s3), None);
+    }
+
+    #[test]
+    fn intersection_degenerate() {
+        let s1 = Segment::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0));
+        let s2 = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+
+        assert_approx_eq!(s1.intersection(&s2).unwrap(), Point::new(1.0, 1.0));
+        assert_approx_eq!(s2.intersection(&s1).unwrap(), Point::new(1.0, 1.0));
+    }
+
     #[test]
     fn horizontal_ray_not_intersectingy() {
         let p1 = Point::new(1.0, 2.0);
@@ -181,6 +399,34 @@ mod tests {
         assert_approx_eq!(segment.end, Point::new(3.0, 4.0));
     }
 
+    #[test]
+    fn affine_transform() {
+        let segment = Segment::new(Point::new(1.0, 2.0), Point::new(2.0, 4.0));
+        let affine = Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+
+        let mapped = &affine * segment;
+        assert_approx_eq!(
+            mapped,
+            Segment::new(Point::new(7.0, -1.0), Point::new(11.0, -3.0))
+        );
+    }
+
+    #[test]
+    fn homography_transform() {
+        let perspective = Homography::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.5, 1.0]]);
+
+        let segment = Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+        let mapped = (&perspective * segment).unwrap();
+        assert_approx_eq!(
+            mapped,
+            Segment::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0))
+        );
+
+        // Both ends are finite, but the segment passes through the horizon at y = -2
+        let crossing = Segment::new(Point::new(0.0, 0.0), Point::new(0.0, -4.0));
+        assert_eq!(&perspective * crossing, None);
+    }
+
     #[bench]
     fn bench_distance_to_point(b: &mut Bencher) {
         let p1 = Point::new(1.0, 2.0);
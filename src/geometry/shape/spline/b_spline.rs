@@ -0,0 +1,284 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        shape::{Chain, CubicBezier, Point, SignedDistance},
+        transform::Similarity,
+        Scalar,
+    },
+    utils::approx::ApproxEq,
+};
+
+const DEGREE: usize = 3;
+
+// Cubic B-spline with the chain vertices as control points. The curve is defined for
+// parameters between `knots[3]` and `knots[n]`, where n is the number of control points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicBSpline {
+    pub control_points: Chain,
+    pub knots: Vec<Scalar>,
+}
+
+impl CubicBSpline {
+    pub fn new(control_points: Chain, knots: Vec<Scalar>) -> Self {
+        assert!(
+            control_points.num_vertices() > DEGREE,
+            "A cubic B-spline needs at least four control points"
+        );
+        assert_eq!(
+            knots.len(),
+            control_points.num_vertices() + DEGREE + 1,
+            "A cubic B-spline needs four more knots than control points"
+        );
+        assert!(
+            knots.windows(2).all(|w| w[0] <= w[1]),
+            "Knots must not decrease"
+        );
+        Self {
+            control_points,
+            knots,
+        }
+    }
+
+    pub fn uniform(control_points: Chain) -> Self {
+        let knots = (0..control_points.num_vertices() + DEGREE + 1)
+            .map(|i| i as Scalar)
+            .collect();
+        Self::new(control_points, knots)
+    }
+
+    // Uniform interior knots with the ends repeated, so the curve starts and ends on the
+    // first and last control points
+    pub fn clamped(control_points: Chain) -> Self {
+        let spans = control_points.num_vertices() - DEGREE;
+        let knots = (0..control_points.num_vertices() + DEGREE + 1)
+            .map(|i| i.saturating_sub(DEGREE).min(spans) as Scalar)
+            .collect();
+        Self::new(control_points, knots)
+    }
+
+    pub fn domain(&self) -> (Scalar, Scalar) {
+        (
+            self.knots[DEGREE],
+            self.knots[self.control_points.num_vertices()],
+        )
+    }
+
+    pub fn point_at(&self, u: Scalar) -> Point {
+        let (start, end) = self.domain();
+        let u = u.clamp(start, end);
+        let span = self.span_of(u);
+        self.blossom(span, [u, u, u])
+    }
+
+    pub fn to_beziers(&self) -> Vec<CubicBezier> {
+        (DEGREE..self.control_points.num_vertices())
+            .filter(|&span| self.knots[span] < self.knots[span + 1])
+            .map(|span| {
+                let (a, b) = (self.knots[span], self.knots[span + 1]);
+                CubicBezier::new(
+                    self.blossom(span, [a, a, a]),
+                    self.blossom(span, [a, a, b]),
+                    self.blossom(span, [a, b, b]),
+                    self.blossom(span, [b, b, b]),
+                )
+            })
+            .collect()
+    }
+
+    pub fn length(&self) -> Scalar {
+        super::length(&self.to_beziers())
+    }
+
+    pub fn resample(&self, num_points: usize) -> Chain {
+        super::resample(&self.to_beziers(), num_points)
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        super::distance_to_point(&self.to_beziers(), point)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.control_points.transform(t);
+    }
+
+    // Last non-empty span starting at or before `u`
+    fn span_of(&self, u: Scalar) -> usize {
+        let last = self.control_points.num_vertices() - 1;
+        let span = self.knots[..=last].partition_point(|&k| k <= u) - 1;
+        let mut span = span.clamp(DEGREE, last);
+        while span > DEGREE && self.knots[span] == self.knots[span + 1] {
+            span -= 1;
+        }
+        span
+    }
+
+    // de Boor's algorithm with a separate parameter at each level. Equal parameters give a
+    // point on the curve, while mixing the span ends gives the Bézier control points.
+    fn blossom(&self, span: usize, parameters: [Scalar; DEGREE]) -> Point {
+        let vertices = &self.control_points.vertices;
+        let mut points: [Point; DEGREE + 1] = [0, 1, 2, 3].map(|i| vertices[span - DEGREE + i]);
+
+        for (level, u) in (1..=DEGREE).zip(parameters) {
+            for j in (level..=DEGREE).rev() {
+                let knot_index = span - DEGREE + j;
+                let low = self.knots[knot_index];
+                let high = self.knots[knot_index + DEGREE + 1 - level];
+                let alpha = (u - low) / (high - low);
+                points[j] = points[j - 1] + (points[j] - points[j - 1]) * alpha;
+            }
+        }
+        points[DEGREE]
+    }
+}
+
+impl SignedDistance for CubicBSpline {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for CubicBSpline {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.control_points
+            .approx_eq(&other.control_points, epsilon)
+            && self.knots.len() == other.knots.len()
+            && self
+                .knots
+                .iter()
+                .zip(&other.knots)
+                .all(|(k1, k2)| k1.approx_eq(k2, epsilon))
+    }
+}
+
+impl Mul<CubicBSpline> for &Similarity {
+    type Output = CubicBSpline;
+
+    fn mul(self, mut rhs: CubicBSpline) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for CubicBSpline {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn control_points() -> Chain {
+        Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 3.0),
+            Point::new(3.0, 3.0),
+            Point::new(4.0, 0.0),
+            Point::new(6.0, 1.0),
+            Point::new(7.0, 4.0),
+        ])
+    }
+
+    #[test]
+    fn uniform_point_at() {
+        let spline = CubicBSpline::uniform(control_points());
+        assert_eq!(spline.domain(), (3.0, 6.0));
+
+        // At a uniform knot the curve sits at (P0 + 4 P1 + P2) / 6
+        let expected = Point::new((0.0 + 4.0 + 3.0) / 6.0, (0.0 + 12.0 + 3.0) / 6.0);
+        assert_approx_eq!(spline.point_at(3.0), expected, 2.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn clamped_interpolates_ends() {
+        let spline = CubicBSpline::clamped(control_points());
+
+        assert_eq!(
+            spline.knots,
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 3.0, 3.0, 3.0]
+        );
+        assert_approx_eq!(spline.point_at(0.0), Point::new(0.0, 0.0));
+        assert_approx_eq!(spline.point_at(3.0), Point::new(7.0, 4.0));
+    }
+
+    #[test]
+    fn beziers_match_curve() {
+        let spline = CubicBSpline::new(
+            control_points(),
+            vec![0.0, 0.0, 0.5, 1.0, 1.0, 2.5, 3.0, 4.0, 4.0, 5.0],
+        );
+        let beziers = spline.to_beziers();
+
+        // The repeated knot at 1 leaves one empty span
+        assert_eq!(beziers.len(), 2);
+        for i in 0..=10 {
+            let t = i as Scalar / 10.0;
+            assert_approx_eq!(
+                beziers[0].point_at(t),
+                spline.point_at(1.0 + 1.5 * t),
+                8.0 * Scalar::EPSILON
+            );
+            assert_approx_eq!(
+                beziers[1].point_at(t),
+                spline.point_at(2.5 + 0.5 * t),
+                16.0 * Scalar::EPSILON
+            );
+        }
+        for pair in beziers.windows(2) {
+            assert_approx_eq!(pair[0].end, pair[1].start);
+        }
+    }
+
+    #[test]
+    fn resample() {
+        let spline = CubicBSpline::clamped(control_points());
+        let chain = spline.resample(40);
+
+        assert_eq!(chain.num_vertices(), 40);
+        assert_approx_eq!(chain.vertices[0], Point::new(0.0, 0.0));
+        assert_approx_eq!(chain.vertices[39], Point::new(7.0, 4.0));
+        let step = spline.length() / 39.0;
+        for segment in chain.segments() {
+            assert!(segment.length() <= step * (1.0 + 64.0 * Scalar::EPSILON));
+            assert!(segment.length() > 0.98 * step);
+        }
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let spline = CubicBSpline::uniform(control_points());
+        let on_curve = spline.point_at(4.3);
+
+        assert_approx_eq!(
+            spline.distance_to_point(on_curve),
+            0.0,
+            8.0 * Scalar::EPSILON
+        );
+        assert!(spline.distance_to_point(Point::new(2.0, 10.0)) > 7.0);
+    }
+
+    #[test]
+    fn transform() {
+        let spline = CubicBSpline::uniform(control_points());
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let transformed = &t * spline.clone();
+
+        for i in 0..=12 {
+            let u = 3.0 + i as Scalar / 4.0;
+            assert_approx_eq!(
+                transformed.point_at(u),
+                &t * spline.point_at(u),
+                16.0 * Scalar::EPSILON
+            );
+        }
+    }
+}
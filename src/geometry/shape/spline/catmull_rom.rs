@@ -0,0 +1,251 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{
+        shape::{Chain, CubicBezier, Point, SignedDistance},
+        transform::Similarity,
+        Scalar,
+    },
+    utils::approx::ApproxEq,
+};
+
+// Interpolates every vertex of the chain. An `alpha` of 0.5 gives the centripetal variant,
+// which neither overshoots nor forms cusps within a segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatmullRom {
+    pub chain: Chain,
+    pub alpha: Scalar,
+}
+
+impl CatmullRom {
+    pub fn new(chain: Chain, alpha: Scalar) -> Self {
+        assert!(
+            chain.num_vertices() > 1,
+            "A Catmull-Rom spline needs at least two vertices"
+        );
+        Self { chain, alpha }
+    }
+
+    pub fn centripetal(chain: Chain) -> Self {
+        Self::new(chain, 0.5)
+    }
+
+    pub fn num_segments(&self) -> usize {
+        self.chain.num_segments()
+    }
+
+    // `t` runs from 0 to the number of segments, one unit per segment
+    pub fn point_at(&self, t: Scalar) -> Point {
+        let index = (t.max(0.0).floor() as usize).min(self.num_segments() - 1);
+        self.segment(index).point_at(t - index as Scalar)
+    }
+
+    pub fn to_beziers(&self) -> Vec<CubicBezier> {
+        (0..self.num_segments()).map(|i| self.segment(i)).collect()
+    }
+
+    // Bézier between vertices `index` and `index + 1`. Closed chains wrap around, open ones
+    // get mirrored phantom end points.
+    fn segment(&self, index: usize) -> CubicBezier {
+        let vertices = &self.chain.vertices;
+        let n = vertices.len();
+        let closed = self.chain.is_closed();
+
+        let before = if index > 0 {
+            vertices[index - 1]
+        } else if closed {
+            vertices[n - 2]
+        } else {
+            vertices[0] + (vertices[0] - vertices[1])
+        };
+        let after = if index + 2 < n {
+            vertices[index + 2]
+        } else if closed {
+            vertices[1]
+        } else {
+            vertices[n - 1] + (vertices[n - 1] - vertices[n - 2])
+        };
+        self.segment_to_bezier(before, vertices[index], vertices[index + 1], after)
+    }
+
+    pub fn length(&self) -> Scalar {
+        super::length(&self.to_beziers())
+    }
+
+    pub fn resample(&self, num_points: usize) -> Chain {
+        super::resample(&self.to_beziers(), num_points)
+    }
+
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        super::distance_to_point(&self.to_beziers(), point)
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.chain.transform(t);
+    }
+
+    // Hermite form of the Barry-Goldman pyramid, with tangents scaled to the middle knot interval
+    fn segment_to_bezier(&self, p0: Point, p1: Point, p2: Point, p3: Point) -> CubicBezier {
+        let dt0 = self.knot_interval(p0, p1);
+        let dt1 = self.knot_interval(p1, p2);
+        let dt2 = self.knot_interval(p2, p3);
+
+        let m1 = ((p1 - p0) / dt0 - (p2 - p0) / (dt0 + dt1) + (p2 - p1) / dt1) * dt1;
+        let m2 = ((p2 - p1) / dt1 - (p3 - p1) / (dt1 + dt2) + (p3 - p2) / dt2) * dt1;
+        CubicBezier::new(p1, p1 + m1 / 3.0, p2 - m2 / 3.0, p2)
+    }
+
+    fn knot_interval(&self, a: Point, b: Point) -> Scalar {
+        let interval = a.distance(b).powf(self.alpha);
+        // Repeated vertices would give empty intervals
+        if interval > 0.0 {
+            interval
+        } else {
+            1.0
+        }
+    }
+}
+
+impl SignedDistance for CatmullRom {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        self.distance_to_point(point)
+    }
+}
+
+impl ApproxEq for CatmullRom {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.chain.approx_eq(&other.chain, epsilon) && self.alpha.approx_eq(&other.alpha, epsilon)
+    }
+}
+
+impl Mul<CatmullRom> for &Similarity {
+    type Output = CatmullRom;
+
+    fn mul(self, mut rhs: CatmullRom) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for CatmullRom {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn spline() -> CatmullRom {
+        CatmullRom::centripetal(Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(4.0, 0.0),
+            Point::new(8.0, 0.5),
+        ]))
+    }
+
+    #[test]
+    fn interpolates_vertices() {
+        let spline = spline();
+
+        for (i, vertex) in spline.chain.vertices().enumerate() {
+            assert_approx_eq!(spline.point_at(i as Scalar), vertex);
+        }
+        assert_eq!(spline.to_beziers().len(), 4);
+    }
+
+    #[test]
+    fn tangent_continuity() {
+        let beziers = spline().to_beziers();
+
+        for pair in beziers.windows(2) {
+            let incoming = pair[0].derivative(1.0).normalize();
+            let outgoing = pair[1].derivative(0.0).normalize();
+            assert_approx_eq!(incoming, outgoing, 2.0 * Scalar::EPSILON);
+        }
+    }
+
+    #[test]
+    fn collinear_vertices_stay_on_line() {
+        let spline = CatmullRom::centripetal(Chain::new(vec![
+            Point::new(0.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(5.0, 1.0),
+            Point::new(6.0, 1.0),
+        ]));
+
+        for i in 0..=30 {
+            let point = spline.point_at(i as Scalar / 10.0);
+            assert_approx_eq!(point.y(), 1.0, 2.0 * Scalar::EPSILON);
+        }
+        assert_approx_eq!(spline.length(), 6.0);
+    }
+
+    #[test]
+    fn closed_chain_is_smooth_at_seam() {
+        let spline = CatmullRom::centripetal(Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]));
+        let beziers = spline.to_beziers();
+
+        let incoming = beziers[beziers.len() - 1].derivative(1.0).normalize();
+        let outgoing = beziers[0].derivative(0.0).normalize();
+        assert_approx_eq!(incoming, outgoing);
+    }
+
+    #[test]
+    fn resample() {
+        let spline = spline();
+        let chain = spline.resample(50);
+
+        assert_eq!(chain.num_vertices(), 50);
+        assert_approx_eq!(chain.vertices[0], Point::new(0.0, 0.0));
+        assert_approx_eq!(chain.vertices[49], Point::new(8.0, 0.5));
+
+        let step = spline.length() / 49.0;
+        for segment in chain.segments() {
+            // Chords are slightly shorter than the arcs they span
+            assert!(segment.length() <= step * (1.0 + 64.0 * Scalar::EPSILON));
+            assert!(segment.length() > 0.98 * step);
+        }
+    }
+
+    #[test]
+    fn distance_to_point() {
+        let spline = spline();
+
+        assert_approx_eq!(spline.distance_to_point(Point::new(3.0, 2.0)), 0.0);
+        assert!(spline.distance_to_point(Point::new(2.0, 5.0)) < 3.0);
+        assert!(spline.distance_to_point(Point::new(2.0, 5.0)) > 2.5);
+    }
+
+    #[test]
+    fn transform() {
+        let spline = spline();
+        let t = Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+        let transformed = &t * spline.clone();
+
+        for i in 0..=20 {
+            let u = i as Scalar / 5.0;
+            assert_approx_eq!(
+                transformed.point_at(u),
+                &t * spline.point_at(u),
+                16.0 * Scalar::EPSILON
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+mod b_spline;
+mod catmull_rom;
+
+use std::iter;
+
+use crate::geometry::Scalar;
+
+use super::{bezier::gauss_legendre, Chain, CubicBezier, Point};
+
+pub use b_spline::*;
+pub use catmull_rom::*;
+
+const MAX_INVERSION_ITERATIONS: usize = 50;
+const ARC_LENGTH_STEPS: usize = 16;
+
+fn length(beziers: &[CubicBezier]) -> Scalar {
+    beziers.iter().map(|b| b.length()).sum()
+}
+
+fn distance_to_point(beziers: &[CubicBezier], point: Point) -> Scalar {
+    beziers
+        .iter()
+        .map(|b| b.distance_to_point(point))
+        .fold(Scalar::INFINITY, Scalar::min)
+}
+
+// Points spaced evenly by arc length, including both ends
+fn resample(beziers: &[CubicBezier], num_points: usize) -> Chain {
+    let (Some(first), Some(last)) = (beziers.first(), beziers.last()) else {
+        return Chain::new(Vec::new());
+    };
+    if num_points < 2 {
+        return Chain::new(vec![first.start; num_points]);
+    }
+
+    let tables: Vec<ArcLengthTable> = beziers.iter().map(ArcLengthTable::new).collect();
+    let mut total = 0.0;
+    let cumulative: Vec<Scalar> = tables
+        .iter()
+        .map(|table| {
+            total += table.total();
+            total
+        })
+        .collect();
+
+    let step = total / (num_points - 1) as Scalar;
+    let mut points: Vec<Point> = (0..num_points - 1)
+        .map(|i| {
+            let distance = step * i as Scalar;
+            let index = cumulative
+                .partition_point(|&l| l <= distance)
+                .min(beziers.len() - 1);
+            let start_distance = if index == 0 {
+                0.0
+            } else {
+                cumulative[index - 1]
+            };
+            let t = tables[index].parameter_at(distance - start_distance);
+            beziers[index].point_at(t)
+        })
+        .collect();
+    points.push(last.end);
+    Chain::new(points)
+}
+
+// Arc lengths of a curve at evenly spaced parameters, so that inverting the arc length only
+// integrates from the closest entry below
+struct ArcLengthTable<'a> {
+    bezier: &'a CubicBezier,
+    lengths: Vec<Scalar>,
+}
+
+impl<'a> ArcLengthTable<'a> {
+    fn new(bezier: &'a CubicBezier) -> Self {
+        let mut total = 0.0;
+        let lengths = iter::once(0.0)
+            .chain((0..ARC_LENGTH_STEPS).map(|i| {
+                total += Self::length_between(bezier, Self::parameter(i), Self::parameter(i + 1));
+                total
+            }))
+            .collect();
+        Self { bezier, lengths }
+    }
+
+    fn total(&self) -> Scalar {
+        self.lengths[ARC_LENGTH_STEPS]
+    }
+
+    // Newton's method within one step of the table, falling back to bisection whenever a
+    // step leaves the bracket
+    fn parameter_at(&self, target: Scalar) -> Scalar {
+        let total = self.total();
+        if total <= 0.0 || target <= 0.0 {
+            return 0.0;
+        }
+        if target >= total {
+            return 1.0;
+        }
+
+        let index = self.lengths.partition_point(|&l| l <= target) - 1;
+        let (start, end) = (self.lengths[index], self.lengths[index + 1]);
+        let origin = Self::parameter(index);
+        let (mut low, mut high) = (origin, Self::parameter(index + 1));
+        let mut t = low + (high - low) * (target - start) / (end - start);
+        for _ in 0..MAX_INVERSION_ITERATIONS {
+            let error = start + Self::length_between(self.bezier, origin, t) - target;
+            if error.abs() <= Scalar::EPSILON * total {
+                break;
+            }
+            if error > 0.0 {
+                high = t;
+            } else {
+                low = t;
+            }
+
+            let speed = self.bezier.derivative(t).norm();
+            let next = t - error / speed;
+            t = if speed > 0.0 && next > low && next < high {
+                next
+            } else {
+                (low + high) / 2.0
+            };
+        }
+        t
+    }
+
+    fn parameter(index: usize) -> Scalar {
+        index as Scalar / ARC_LENGTH_STEPS as Scalar
+    }
+
+    fn length_between(bezier: &CubicBezier, a: Scalar, b: Scalar) -> Scalar {
+        gauss_legendre(&|t| bezier.derivative(t).norm(), a, b)
+    }
+}
@@ -0,0 +1,634 @@
+mod status;
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+use crate::geometry::Scalar;
+
+use self::status::Status;
+
+use super::{Chain, Point, Segment};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentIntersection {
+    pub point: Point,
+    pub segments: (usize, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainIntersection {
+    pub point: Point,
+    pub first: (usize, usize),
+    pub second: (usize, usize),
+}
+
+pub fn segment_intersections(segments: &[Segment]) -> Vec<SegmentIntersection> {
+    Sweep::new(segments).run()
+}
+
+pub fn chain_intersections(chains: &[Chain]) -> Vec<ChainIntersection> {
+    let mut segments = Vec::new();
+    let mut owners = Vec::new();
+    for (chain_index, chain) in chains.iter().enumerate() {
+        for (segment_index, segment) in chain.segments().enumerate() {
+            segments.push(segment);
+            owners.push((chain_index, segment_index));
+        }
+    }
+
+    segment_intersections(&segments)
+        .into_iter()
+        .map(|i| (i.point, owners[i.segments.0], owners[i.segments.1]))
+        .filter(|&(_, first, second)| {
+            first.0 != second.0 || !chains[first.0].touch_at_shared_vertex(first.1, second.1)
+        })
+        .map(|(point, first, second)| ChainIntersection {
+            point,
+            first,
+            second,
+        })
+        .collect()
+}
+
+impl Chain {
+    pub fn self_intersections(&self) -> Vec<SegmentIntersection> {
+        let segments: Vec<Segment> = self.segments().collect();
+        segment_intersections(&segments)
+            .into_iter()
+            .filter(|i| !self.touch_at_shared_vertex(i.segments.0, i.segments.1))
+            .collect()
+    }
+
+    pub fn is_simple(&self) -> bool {
+        self.self_intersections().is_empty()
+    }
+
+    // Whether two segments only meet because they are joined by a vertex, possibly with
+    // duplicated vertices in between, without folding back onto each other.
+    fn touch_at_shared_vertex(&self, first: usize, second: usize) -> bool {
+        let (first, second) = (first.min(second), first.max(second));
+        let last = self.num_vertices() - 1;
+        let vertices = &self.vertices;
+
+        let (incoming, outgoing) = if vertices[first + 1..=second]
+            .iter()
+            .all(|&v| v == vertices[second])
+        {
+            (first, second)
+        } else if vertices[0] == vertices[last]
+            && vertices[second + 1..]
+                .iter()
+                .chain(&vertices[..=first])
+                .all(|&v| v == vertices[0])
+        {
+            (second, first)
+        } else {
+            return false;
+        };
+
+        let d1 = vertices[incoming + 1] - vertices[incoming];
+        let d2 = vertices[outgoing + 1] - vertices[outgoing];
+        d1.perp_dot(d2) != 0.0 || d1.dot(d2) >= 0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct EventPoint(Point);
+
+impl EventPoint {
+    fn new(point: Point) -> Self {
+        // Adding zero turns -0.0 into 0.0, so that both end up in the same event
+        Self(Point::new(point.x() + 0.0, point.y() + 0.0))
+    }
+}
+
+impl PartialEq for EventPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EventPoint {}
+
+impl PartialOrd for EventPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EventPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .x()
+            .total_cmp(&other.0.x())
+            .then_with(|| self.0.y().total_cmp(&other.0.y()))
+    }
+}
+
+#[derive(Default)]
+struct Event {
+    starting: Vec<usize>,
+    crossing: Vec<(usize, usize)>,
+}
+
+// Bentley-Ottmann sweep from left to right, where events with the same x coordinate are
+// handled from bottom to top.
+struct Sweep {
+    segments: Vec<Segment>,
+    events: BTreeMap<EventPoint, Event>,
+    status: Status,
+    in_status: Vec<bool>,
+    tolerance: Scalar,
+    reported: HashSet<(usize, usize)>,
+    intersections: Vec<SegmentIntersection>,
+}
+
+impl Sweep {
+    fn new(segments: &[Segment]) -> Self {
+        let magnitude = segments
+            .iter()
+            .flat_map(|s| [s.start, s.end])
+            .map(|p| p.x().abs().max(p.y().abs()))
+            .fold(0.0, Scalar::max);
+        let tolerance = 256.0 * Scalar::EPSILON * magnitude;
+
+        // Nearly vertical segments are made vertical, as the sweep could otherwise place
+        // their intersections beyond their end points
+        let segments: Vec<Segment> = segments
+            .iter()
+            .map(|s| {
+                let mut end = s.end;
+                if (end.x() - s.start.x()).abs() <= tolerance {
+                    end = Point::new(s.start.x(), end.y());
+                }
+                if EventPoint::new(end) < EventPoint::new(s.start) {
+                    Segment::new(end, s.start)
+                } else {
+                    Segment::new(s.start, end)
+                }
+            })
+            .collect();
+
+        let mut events: BTreeMap<EventPoint, Event> = BTreeMap::new();
+        for (index, segment) in segments.iter().enumerate() {
+            events
+                .entry(EventPoint::new(segment.start))
+                .or_default()
+                .starting
+                .push(index);
+            events.entry(EventPoint::new(segment.end)).or_default();
+        }
+
+        Self {
+            status: Status::new(segments.len()),
+            in_status: vec![false; segments.len()],
+            tolerance,
+            reported: HashSet::new(),
+            intersections: Vec::new(),
+            segments,
+            events,
+        }
+    }
+
+    fn run(mut self) -> Vec<SegmentIntersection> {
+        while let Some((EventPoint(point), event)) = self.events.pop_first() {
+            self.handle_event(point, event);
+        }
+        self.intersections
+    }
+
+    fn handle_event(&mut self, point: Point, event: Event) {
+        let mut through = self.segments_through(point);
+        for &(a, b) in &event.crossing {
+            for s in [a, b] {
+                if self.in_status[s] && !through.contains(&s) {
+                    through.push(s);
+                }
+            }
+        }
+
+        let mut involved = event.starting.clone();
+        involved.extend(&through);
+        for (i, &a) in involved.iter().enumerate() {
+            for &b in &involved[i + 1..] {
+                self.report(point, a, b);
+            }
+        }
+        for &(a, b) in &event.crossing {
+            self.report(point, a, b);
+        }
+
+        for &s in &through {
+            self.status.remove(s);
+            self.in_status[s] = false;
+        }
+
+        let inserted: Vec<usize> = event
+            .starting
+            .iter()
+            .copied()
+            .filter(|&s| self.segments[s].start != self.segments[s].end)
+            .chain(
+                through
+                    .iter()
+                    .copied()
+                    .filter(|&s| EventPoint::new(self.segments[s].end) > EventPoint::new(point)),
+            )
+            .collect();
+        for &s in &inserted {
+            let (segments, tolerance) = (&self.segments, self.tolerance);
+            self.status
+                .insert(s, |t| compare_after(segments, tolerance, point, s, t));
+            self.in_status[s] = true;
+        }
+
+        if inserted.is_empty() {
+            let above = self.lower_bound(point);
+            let below = match above {
+                Some(above) => self.status.prev(above),
+                None => self.status.last(),
+            };
+            self.check(point, below, above);
+        } else {
+            for &s in &inserted {
+                let below = self.status.prev(s);
+                if below.is_none_or(|below| !inserted.contains(&below)) {
+                    self.check(point, below, Some(s));
+                }
+                let above = self.status.next(s);
+                if above.is_none_or(|above| !inserted.contains(&above)) {
+                    self.check(point, Some(s), above);
+                }
+            }
+        }
+    }
+
+    fn segments_through(&self, point: Point) -> Vec<usize> {
+        let mut through = Vec::new();
+        let mut current = self.lower_bound(point);
+        while let Some(s) = current {
+            if self.segments[s].distance_to_point(point) > self.tolerance {
+                break;
+            }
+            through.push(s);
+            current = self.status.next(s);
+        }
+        through
+    }
+
+    fn lower_bound(&self, point: Point) -> Option<usize> {
+        self.status
+            .lower_bound(|s| sweep_y(&self.segments[s], point) >= point.y() - self.tolerance)
+    }
+
+    fn check(&mut self, point: Point, below: Option<usize>, above: Option<usize>) {
+        let (Some(below), Some(above)) = (below, above) else {
+            return;
+        };
+        let Some(mut intersection) = self.segments[below].intersection(&self.segments[above])
+        else {
+            return;
+        };
+        if let Some(vertical) = [below, above]
+            .map(|s| &self.segments[s])
+            .into_iter()
+            .find(|s| s.start.x() == s.end.x())
+        {
+            intersection = Point::new(vertical.start.x(), intersection.y());
+        }
+
+        if EventPoint::new(intersection) > EventPoint::new(point) {
+            self.events
+                .entry(EventPoint::new(intersection))
+                .or_default()
+                .crossing
+                .push((below, above));
+        } else if intersection.distance(point) <= self.tolerance {
+            self.report(point, below, above);
+        }
+    }
+
+    fn report(&mut self, point: Point, a: usize, b: usize) {
+        let pair = (a.min(b), a.max(b));
+        if self.reported.insert(pair) {
+            self.intersections.push(SegmentIntersection {
+                point,
+                segments: pair,
+            });
+        }
+    }
+}
+
+fn sweep_y(segment: &Segment, point: Point) -> Scalar {
+    let (start, end) = (segment.start, segment.end);
+    if start.x() == end.x() {
+        return point.y().clamp(start.y(), end.y());
+    }
+    if point.x() == start.x() {
+        return start.y();
+    }
+    if point.x() == end.x() {
+        return end.y();
+    }
+    start.y() + (point.x() - start.x()) * (end.y() - start.y()) / (end.x() - start.x())
+}
+
+fn slope(segment: &Segment) -> Scalar {
+    let direction = segment.as_vector();
+    if direction.x == 0.0 {
+        Scalar::INFINITY
+    } else {
+        direction.y / direction.x
+    }
+}
+
+// Order of segment `s`, which passes through `point`, relative to segment `t` immediately
+// after the sweep line has passed `point`.
+fn compare_after(
+    segments: &[Segment],
+    tolerance: Scalar,
+    point: Point,
+    s: usize,
+    t: usize,
+) -> Ordering {
+    let y = sweep_y(&segments[t], point);
+    if y < point.y() - tolerance {
+        return Ordering::Greater;
+    }
+    if y > point.y() + tolerance {
+        return Ordering::Less;
+    }
+    slope(&segments[s])
+        .total_cmp(&slope(&segments[t]))
+        .then(s.cmp(&t))
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{geometry::shape::dist, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    fn sorted_pairs(intersections: &[SegmentIntersection]) -> Vec<(usize, usize)> {
+        let mut pairs: Vec<(usize, usize)> = intersections.iter().map(|i| i.segments).collect();
+        pairs.sort();
+        pairs
+    }
+
+    fn brute_force_pairs(segments: &[Segment]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, s1) in segments.iter().enumerate() {
+            for (j, s2) in segments.iter().enumerate().skip(i + 1) {
+                if s1.intersects(s2) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn pseudo_random_segments(n: usize, seed: u64) -> Vec<Segment> {
+        let mut state = seed;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 40) as Scalar / (1u64 << 24) as Scalar * 100.0
+        };
+        (0..n)
+            .map(|_| {
+                let start = Point::new(next(), next());
+                let end = Point::new(
+                    start.x() + next() / 5.0 - 10.0,
+                    start.y() + next() / 5.0 - 10.0,
+                );
+                Segment::new(start, end)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crossing() {
+        let segments = [
+            Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+            Segment::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].segments, (0, 1));
+        assert_approx_eq!(intersections[0].point, Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn disjoint() {
+        let segments = [
+            Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+            Segment::new(Point::new(2.0, 0.0), Point::new(3.0, 1.0)),
+            Segment::new(Point::new(-1.0, 3.0), Point::new(3.0, 3.0)),
+        ];
+        assert_eq!(segment_intersections(&segments), vec![]);
+    }
+
+    #[test]
+    fn touching() {
+        let segments = [
+            Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+            Segment::new(Point::new(2.0, 0.0), Point::new(1.0, 1.0)),
+            Segment::new(Point::new(2.0, 2.0), Point::new(3.0, 0.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(sorted_pairs(&intersections), vec![(0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn many_through_one_point() {
+        let segments = [
+            Segment::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0)),
+            Segment::new(Point::new(-1.0, 1.0), Point::new(1.0, -1.0)),
+            Segment::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0)),
+            Segment::new(Point::new(0.0, -1.0), Point::new(0.0, 1.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(
+            sorted_pairs(&intersections),
+            vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+        );
+        for intersection in intersections {
+            assert_approx_eq!(intersection.point, Point::ORIGIN);
+        }
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let segments = [
+            Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+            Segment::new(Point::new(3.0, 3.0), Point::new(1.0, 1.0)),
+            Segment::new(Point::new(3.0, 0.0), Point::new(5.0, 0.0)),
+            Segment::new(Point::new(5.5, 0.0), Point::new(7.0, 0.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].segments, (0, 1));
+        assert_approx_eq!(intersections[0].point, Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn vertical() {
+        let segments = [
+            Segment::new(Point::new(1.0, -1.0), Point::new(1.0, 3.0)),
+            Segment::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0)),
+            Segment::new(Point::new(0.0, 2.0), Point::new(2.0, 2.0)),
+            Segment::new(Point::new(1.0, 4.0), Point::new(1.0, 5.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(sorted_pairs(&intersections), vec![(0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn grid() {
+        let mut segments = Vec::new();
+        for i in 0..5 {
+            let offset = i as Scalar;
+            segments.push(Segment::new(
+                Point::new(-1.0, offset),
+                Point::new(5.0, offset + 0.5),
+            ));
+            segments.push(Segment::new(
+                Point::new(offset, -1.0),
+                Point::new(offset + 0.5, 6.0),
+            ));
+        }
+
+        assert_eq!(segment_intersections(&segments).len(), 25);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // The tolerance of the sweep for coordinates up to 110, within which segments that
+        // only come close count as touching
+        let tolerance = 256.0 * Scalar::EPSILON * 110.0;
+        for seed in 0..20 {
+            let segments = pseudo_random_segments(60, seed);
+            let found = sorted_pairs(&segment_intersections(&segments));
+            let expected = brute_force_pairs(&segments);
+
+            assert!(expected.iter().all(|pair| found.contains(pair)));
+            for &(i, j) in found.iter().filter(|pair| !expected.contains(pair)) {
+                assert!(dist::segment_to_segment(&segments[i], &segments[j]) <= tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn nearly_vertical() {
+        let segments = [
+            Segment::new(
+                Point::new(0.3 - Scalar::EPSILON, 9.0),
+                Point::new(0.1 + 0.2, 0.0),
+            ),
+            Segment::new(Point::new(0.0, 0.3), Point::new(0.4, 0.35)),
+            Segment::new(Point::new(0.0, 5.0), Point::new(1.0, 6.0)),
+            Segment::new(Point::new(0.1, 8.0), Point::new(0.6, 7.0)),
+        ];
+        let intersections = segment_intersections(&segments);
+
+        assert_eq!(sorted_pairs(&intersections), vec![(0, 1), (0, 2), (0, 3)]);
+    }
+
+    #[test]
+    fn simple_chain() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        assert!(chain.is_simple());
+    }
+
+    #[test]
+    fn simple_chain_with_duplicate_vertices() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        assert!(chain.is_simple());
+    }
+
+    #[test]
+    fn figure_eight() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 0.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 0.0),
+        ]);
+        let intersections = chain.self_intersections();
+
+        assert!(!chain.is_simple());
+        assert_eq!(intersections.len(), 1);
+        assert_eq!(intersections[0].segments, (0, 2));
+        assert_approx_eq!(intersections[0].point, Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn folding_back() {
+        let chain = Chain::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0),
+        ]);
+        assert!(!chain.is_simple());
+    }
+
+    #[test]
+    fn chains() {
+        let chains = [
+            Chain::new(vec![
+                Point::new(0.0, 0.0),
+                Point::new(2.0, 0.0),
+                Point::new(2.0, 2.0),
+            ]),
+            Chain::new(vec![
+                Point::new(1.0, -1.0),
+                Point::new(1.0, 1.0),
+                Point::new(3.0, 1.0),
+            ]),
+        ];
+        let mut intersections = chain_intersections(&chains);
+        intersections.sort_by_key(|i| (i.first, i.second));
+
+        assert_eq!(intersections.len(), 2);
+        assert_eq!(
+            (intersections[0].first, intersections[0].second),
+            ((0, 0), (1, 0))
+        );
+        assert_approx_eq!(intersections[0].point, Point::new(1.0, 0.0));
+        assert_eq!(
+            (intersections[1].first, intersections[1].second),
+            ((0, 1), (1, 1))
+        );
+        assert_approx_eq!(intersections[1].point, Point::new(2.0, 1.0));
+    }
+
+    #[bench]
+    fn segment_intersections_1000_bench(b: &mut Bencher) {
+        let segments = pseudo_random_segments(1000, 42);
+
+        b.iter(|| black_box(segment_intersections(&segments)));
+    }
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+
+// Treap over segment indices, where every segment is its own node. The order is
+// defined by the sweep line at the time of insertion, so nodes are removed and
+// traversed by handle rather than by comparison.
+pub(super) struct Status {
+    root: usize,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    parent: Vec<usize>,
+    priority: Vec<u64>,
+}
+
+impl Status {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            root: NIL,
+            left: vec![NIL; capacity],
+            right: vec![NIL; capacity],
+            parent: vec![NIL; capacity],
+            priority: (0..capacity as u64).map(splitmix).collect(),
+        }
+    }
+
+    pub fn insert(&mut self, node: usize, mut cmp: impl FnMut(usize) -> Ordering) {
+        let mut parent = NIL;
+        let mut current = self.root;
+        let mut goes_left = false;
+        while current != NIL {
+            parent = current;
+            goes_left = cmp(current) == Ordering::Less;
+            current = if goes_left {
+                self.left[current]
+            } else {
+                self.right[current]
+            };
+        }
+
+        self.parent[node] = parent;
+        if parent == NIL {
+            self.root = node;
+        } else if goes_left {
+            self.left[parent] = node;
+        } else {
+            self.right[parent] = node;
+        }
+
+        while self.parent[node] != NIL && self.priority[node] > self.priority[self.parent[node]] {
+            self.rotate_up(node);
+        }
+    }
+
+    pub fn remove(&mut self, node: usize) {
+        loop {
+            let (left, right) = (self.left[node], self.right[node]);
+            let child = match (left, right) {
+                (NIL, NIL) => break,
+                (NIL, child) | (child, NIL) => child,
+                _ if self.priority[left] > self.priority[right] => left,
+                _ => right,
+            };
+            self.rotate_up(child);
+        }
+
+        let parent = self.parent[node];
+        if parent == NIL {
+            self.root = NIL;
+        } else if self.left[parent] == node {
+            self.left[parent] = NIL;
+        } else {
+            self.right[parent] = NIL;
+        }
+        self.parent[node] = NIL;
+    }
+
+    pub fn prev(&self, node: usize) -> Option<usize> {
+        if self.left[node] != NIL {
+            return Some(self.rightmost(self.left[node]));
+        }
+        let mut current = node;
+        let mut parent = self.parent[current];
+        while parent != NIL && self.left[parent] == current {
+            current = parent;
+            parent = self.parent[current];
+        }
+        (parent != NIL).then_some(parent)
+    }
+
+    pub fn next(&self, node: usize) -> Option<usize> {
+        if self.right[node] != NIL {
+            return Some(self.leftmost(self.right[node]));
+        }
+        let mut current = node;
+        let mut parent = self.parent[current];
+        while parent != NIL && self.right[parent] == current {
+            current = parent;
+            parent = self.parent[current];
+        }
+        (parent != NIL).then_some(parent)
+    }
+
+    pub fn last(&self) -> Option<usize> {
+        (self.root != NIL).then(|| self.rightmost(self.root))
+    }
+
+    // First node for which `pred` holds, assuming `pred` is monotone in the order.
+    pub fn lower_bound(&self, mut pred: impl FnMut(usize) -> bool) -> Option<usize> {
+        let mut result = None;
+        let mut current = self.root;
+        while current != NIL {
+            if pred(current) {
+                result = Some(current);
+                current = self.left[current];
+            } else {
+                current = self.right[current];
+            }
+        }
+        result
+    }
+
+    fn leftmost(&self, mut node: usize) -> usize {
+        while self.left[node] != NIL {
+            node = self.left[node];
+        }
+        node
+    }
+
+    fn rightmost(&self, mut node: usize) -> usize {
+        while self.right[node] != NIL {
+            node = self.right[node];
+        }
+        node
+    }
+
+    fn rotate_up(&mut self, node: usize) {
+        let parent = self.parent[node];
+        let grandparent = self.parent[parent];
+
+        if self.left[parent] == node {
+            let moved = self.right[node];
+            self.left[parent] = moved;
+            if moved != NIL {
+                self.parent[moved] = parent;
+            }
+            self.right[node] = parent;
+        } else {
+            let moved = self.left[node];
+            self.right[parent] = moved;
+            if moved != NIL {
+                self.parent[moved] = parent;
+            }
+            self.left[node] = parent;
+        }
+        self.parent[parent] = node;
+
+        self.parent[node] = grandparent;
+        if grandparent == NIL {
+            self.root = node;
+        } else if self.left[grandparent] == parent {
+            self.left[grandparent] = node;
+        } else {
+            self.right[grandparent] = node;
+        }
+    }
+}
+
+fn splitmix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_sorted(status: &mut Status, keys: &[i32], node: usize) {
+        status.insert(node, |other| keys[node].cmp(&keys[other]));
+    }
+
+    fn in_order(status: &Status) -> Vec<usize> {
+        let mut nodes = Vec::new();
+        let mut current = status.lower_bound(|_| true);
+        while let Some(node) = current {
+            nodes.push(node);
+            current = status.next(node);
+        }
+        nodes
+    }
+
+    #[test]
+    fn insert_and_traverse() {
+        let keys = [5, 3, 8, 1, 4, 7, 9, 2, 6, 0];
+        let mut status = Status::new(keys.len());
+        for node in 0..keys.len() {
+            insert_sorted(&mut status, &keys, node);
+        }
+
+        assert_eq!(in_order(&status), vec![9, 3, 7, 1, 4, 0, 8, 5, 2, 6]);
+        assert_eq!(status.last(), Some(6));
+        assert_eq!(status.prev(9), None);
+        assert_eq!(status.prev(4), Some(1));
+        assert_eq!(status.next(6), None);
+    }
+
+    #[test]
+    fn remove() {
+        let keys = [5, 3, 8, 1, 4, 7, 9, 2, 6, 0];
+        let mut status = Status::new(keys.len());
+        for node in 0..keys.len() {
+            insert_sorted(&mut status, &keys, node);
+        }
+
+        status.remove(0);
+        status.remove(9);
+        status.remove(6);
+        assert_eq!(in_order(&status), vec![3, 7, 1, 4, 8, 5, 2]);
+
+        insert_sorted(&mut status, &keys, 0);
+        assert_eq!(in_order(&status), vec![3, 7, 1, 4, 0, 8, 5, 2]);
+    }
+
+    #[test]
+    fn lower_bound() {
+        let keys = [5, 3, 8, 1];
+        let mut status = Status::new(keys.len());
+        for node in 0..keys.len() {
+            insert_sorted(&mut status, &keys, node);
+        }
+
+        assert_eq!(status.lower_bound(|node| keys[node] >= 4), Some(0));
+        assert_eq!(status.lower_bound(|node| keys[node] >= 3), Some(1));
+        assert_eq!(status.lower_bound(|node| keys[node] >= 9), None);
+    }
+}
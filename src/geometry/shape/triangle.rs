@@ -0,0 +1,337 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{transform::Similarity, Scalar},
+    utils::approx::ApproxEq,
+};
+
+use super::{Circle, Point, Segment, SignedDistance};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Triangle {
+    pub const fn new(a: Point, b: Point, c: Point) -> Self {
+        Self { a, b, c }
+    }
+
+    pub fn vertices(&self) -> [Point; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    pub fn edges(&self) -> [Segment; 3] {
+        [
+            Segment::new(self.a, self.b),
+            Segment::new(self.b, self.c),
+            Segment::new(self.c, self.a),
+        ]
+    }
+
+    // Positive for counterclockwise vertices
+    pub fn signed_area(&self) -> Scalar {
+        0.5 * (self.b - self.a).perp_dot(self.c - self.a)
+    }
+
+    pub fn area(&self) -> Scalar {
+        self.signed_area().abs()
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Orientation::CounterClockwise
+        } else if area < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    // Weights of `a`, `b` and `c` that reproduce the point. Degenerate triangles have none.
+    pub fn barycentric(&self, point: Point) -> Option<[Scalar; 3]> {
+        let double_area = 2.0 * self.signed_area();
+        if double_area == 0.0 {
+            return None;
+        }
+
+        let u = (self.c - self.b).perp_dot(point - self.b) / double_area;
+        let v = (self.a - self.c).perp_dot(point - self.c) / double_area;
+        Some([u, v, 1.0 - u - v])
+    }
+
+    pub fn point_from_barycentric(&self, weights: [Scalar; 3]) -> Point {
+        Point::from(
+            self.a.as_vector() * weights[0]
+                + self.b.as_vector() * weights[1]
+                + self.c.as_vector() * weights[2],
+        )
+    }
+
+    // Boundary points count as inside
+    pub fn contains(&self, point: Point) -> bool {
+        let d1 = (self.b - self.a).perp_dot(point - self.a);
+        let d2 = (self.c - self.b).perp_dot(point - self.b);
+        let d3 = (self.a - self.c).perp_dot(point - self.c);
+        let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+        let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+        if has_negative && has_positive {
+            return false;
+        }
+
+        // All signs agree, which for a degenerate triangle only means the point is on its line
+        self.orientation() != Orientation::Collinear
+            || self
+                .edges()
+                .iter()
+                .any(|e| e.distance_to_point(point) == 0.0)
+    }
+
+    pub fn closest_point(&self, point: Point) -> Point {
+        if self.contains(point) {
+            return point;
+        }
+
+        self.edges()
+            .iter()
+            .map(|e| e.closest_point(point))
+            .min_by(|p1, p2| point.distance(*p1).total_cmp(&point.distance(*p2)))
+            .unwrap()
+    }
+
+    // Zero anywhere inside
+    pub fn distance_to_point(&self, point: Point) -> Scalar {
+        if self.contains(point) {
+            return 0.0;
+        }
+        self.boundary_distance(point)
+    }
+
+    // Negative inside, with the depth measured to the nearest edge
+    pub fn signed_distance(&self, point: Point) -> Scalar {
+        let distance = self.boundary_distance(point);
+        if self.contains(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    pub fn circumcircle(&self) -> Option<Circle> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let denominator = 2.0 * ab.perp_dot(ac);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let offset = (ac.perp() * -ab.norm_sq() + ab.perp() * ac.norm_sq()) / denominator;
+        Some(Circle::new(self.a + offset, offset.norm()))
+    }
+
+    pub fn incircle(&self) -> Option<Circle> {
+        let len_a = self.b.distance(self.c);
+        let len_b = self.c.distance(self.a);
+        let len_c = self.a.distance(self.b);
+        let perimeter = len_a + len_b + len_c;
+        if self.orientation() == Orientation::Collinear || perimeter == 0.0 {
+            return None;
+        }
+
+        let center =
+            self.point_from_barycentric([len_a / perimeter, len_b / perimeter, len_c / perimeter]);
+        Some(Circle::new(center, 2.0 * self.area() / perimeter))
+    }
+
+    pub fn transform(&mut self, t: &Similarity) {
+        self.a *= t;
+        self.b *= t;
+        self.c *= t;
+    }
+
+    fn boundary_distance(&self, point: Point) -> Scalar {
+        self.edges()
+            .iter()
+            .map(|e| e.distance_to_point(point))
+            .fold(Scalar::INFINITY, Scalar::min)
+    }
+}
+
+impl SignedDistance for Triangle {
+    fn signed_distance(&self, point: Point) -> Scalar {
+        Triangle::signed_distance(self, point)
+    }
+}
+
+impl ApproxEq for Triangle {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.a.approx_eq(&other.a, epsilon)
+            && self.b.approx_eq(&other.b, epsilon)
+            && self.c.approx_eq(&other.c, epsilon)
+    }
+}
+
+impl Mul<Triangle> for &Similarity {
+    type Output = Triangle;
+
+    fn mul(self, mut rhs: Triangle) -> Self::Output {
+        rhs.transform(self);
+        rhs
+    }
+}
+
+impl MulAssign<&Similarity> for Triangle {
+    fn mul_assign(&mut self, rhs: &Similarity) {
+        self.transform(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test::{black_box, Bencher};
+
+    use crate::{
+        geometry::{scalar::consts::FRAC_PI_2, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn area_and_orientation() {
+        let triangle = triangle();
+        assert_approx_eq!(triangle.signed_area(), 6.0);
+        assert_eq!(triangle.orientation(), Orientation::CounterClockwise);
+
+        let reversed = Triangle::new(triangle.a, triangle.c, triangle.b);
+        assert_approx_eq!(reversed.signed_area(), -6.0);
+        assert_approx_eq!(reversed.area(), 6.0);
+        assert_eq!(reversed.orientation(), Orientation::Clockwise);
+
+        let flat = Triangle::new(Point::ORIGIN, Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert_eq!(flat.orientation(), Orientation::Collinear);
+    }
+
+    #[test]
+    fn barycentric() {
+        let triangle = triangle();
+
+        let weights = triangle.barycentric(Point::new(1.0, 1.0)).unwrap();
+        assert_approx_eq!(weights[0], 5.0 / 12.0);
+        assert_approx_eq!(weights[1], 1.0 / 4.0);
+        assert_approx_eq!(weights[2], 1.0 / 3.0);
+        assert_approx_eq!(
+            triangle.point_from_barycentric(weights),
+            Point::new(1.0, 1.0)
+        );
+
+        assert_eq!(
+            triangle.barycentric(Point::new(4.0, 0.0)),
+            Some([0.0, 1.0, 0.0])
+        );
+
+        let flat = Triangle::new(Point::ORIGIN, Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert_eq!(flat.barycentric(Point::ORIGIN), None);
+    }
+
+    #[test]
+    fn contains() {
+        let triangle = triangle();
+
+        assert!(triangle.contains(Point::new(1.0, 1.0)));
+        assert!(triangle.contains(Point::new(2.0, 0.0)));
+        assert!(triangle.contains(Point::new(0.0, 3.0)));
+        assert!(!triangle.contains(Point::new(3.0, 3.0)));
+        assert!(!triangle.contains(Point::new(-0.1, 1.0)));
+
+        let reversed = Triangle::new(triangle.a, triangle.c, triangle.b);
+        assert!(reversed.contains(Point::new(1.0, 1.0)));
+
+        let flat = Triangle::new(Point::ORIGIN, Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert!(flat.contains(Point::new(2.0, 2.0)));
+        assert!(!flat.contains(Point::new(4.0, 4.0)));
+    }
+
+    #[test]
+    fn distance_matches_edges() {
+        let triangle = triangle();
+
+        for point in [
+            Point::new(5.0, 5.0),
+            Point::new(-1.0, -1.0),
+            Point::new(2.0, -3.0),
+            Point::new(6.0, 0.5),
+        ] {
+            let edge_distance = triangle
+                .edges()
+                .iter()
+                .map(|e| e.distance_to_point(point))
+                .fold(Scalar::INFINITY, Scalar::min);
+            assert_approx_eq!(triangle.distance_to_point(point), edge_distance);
+            assert_approx_eq!(point.distance(triangle.closest_point(point)), edge_distance);
+        }
+
+        assert_approx_eq!(triangle.distance_to_point(Point::new(1.0, 1.0)), 0.0);
+        assert_approx_eq!(triangle.signed_distance(Point::new(1.0, 0.5)), -0.5);
+        assert_approx_eq!(triangle.signed_distance(Point::new(5.0, 0.0)), 1.0);
+    }
+
+    #[test]
+    fn circumcircle() {
+        let circle = triangle().circumcircle().unwrap();
+        assert_approx_eq!(circle, Circle::new(Point::new(2.0, 1.5), 2.5));
+
+        let flat = Triangle::new(Point::ORIGIN, Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+        assert_eq!(flat.circumcircle(), None);
+    }
+
+    #[test]
+    fn incircle() {
+        let circle = triangle().incircle().unwrap();
+        assert_approx_eq!(circle, Circle::new(Point::new(1.0, 1.0), 1.0));
+    }
+
+    #[test]
+    fn transform() {
+        let mut triangle = triangle();
+        triangle *= &Similarity::from_parts(2.0, FRAC_PI_2, vector!(1.0, 0.0));
+
+        assert_approx_eq!(
+            triangle,
+            Triangle::new(
+                Point::new(1.0, 0.0),
+                Point::new(1.0, 8.0),
+                Point::new(-5.0, 0.0)
+            ),
+            8.0 * Scalar::EPSILON
+        );
+    }
+
+    #[bench]
+    fn distance_to_point_bench(b: &mut Bencher) {
+        let triangle = triangle();
+        let point = Point::new(5.0, 5.0);
+
+        b.iter(|| black_box(triangle.distance_to_point(point)));
+    }
+}
@@ -0,0 +1,307 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{vector, Scalar, Vector},
+    utils::approx::{approx_eq, ApproxEq},
+};
+
+use super::{ScaledRotation, Similarity, Translation};
+
+// General linear map followed by a translation. Unlike `Similarity` it can scale the axes
+// differently, shear and mirror.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affine {
+    x_axis: Vector,
+    y_axis: Vector,
+    translation: Translation,
+}
+
+// Pieces of an affine map, which applies the shear, then the scale, then the rotation and
+// finally the translation. A negative `scale.y` mirrors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AffineParts {
+    pub translation: Vector,
+    pub rotation: Scalar,
+    pub scale: Vector,
+    pub shear: Scalar,
+}
+
+impl Affine {
+    pub const IDENT: Self = Self::new(Vector::X, Vector::Y, Translation::IDENT);
+
+    // Columns of the linear part, i.e. where the unit axes end up
+    pub const fn new(x_axis: Vector, y_axis: Vector, translation: Translation) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            translation,
+        }
+    }
+
+    // Rows of the 2x3 matrix [a b c; d e f], which maps (x, y) to
+    // (a x + b y + c, d x + e y + f)
+    pub const fn from_matrix(matrix: [[Scalar; 3]; 2]) -> Self {
+        let [[a, b, c], [d, e, f]] = matrix;
+        Self::new(vector!(a, d), vector!(b, e), Translation::new(c, f))
+    }
+
+    pub const fn from_scale(scale: Vector) -> Self {
+        Self::new(
+            vector!(scale.x, 0.0),
+            vector!(0.0, scale.y),
+            Translation::IDENT,
+        )
+    }
+
+    // Moves every point along x by `shear` times its y
+    pub const fn from_shear(shear: Scalar) -> Self {
+        Self::new(Vector::X, vector!(shear, 1.0), Translation::IDENT)
+    }
+
+    pub fn from_parts(parts: &AffineParts) -> Self {
+        let rotation = ScaledRotation::from_parts(1.0, parts.rotation);
+        Self::new(
+            rotation.apply(vector!(parts.scale.x, 0.0)),
+            rotation.apply(vector!(parts.scale.x * parts.shear, parts.scale.y)),
+            Translation::from_vector(parts.translation),
+        )
+    }
+
+    pub const fn matrix(&self) -> [[Scalar; 3]; 2] {
+        let t = self.translation.as_vector();
+        [
+            [self.x_axis.x, self.y_axis.x, t.x],
+            [self.x_axis.y, self.y_axis.y, t.y],
+        ]
+    }
+
+    pub const fn x_axis(&self) -> Vector {
+        self.x_axis
+    }
+
+    pub const fn y_axis(&self) -> Vector {
+        self.y_axis
+    }
+
+    pub const fn translation(&self) -> Translation {
+        self.translation
+    }
+
+    // Negative when the map mirrors, zero when it collapses the plane
+    pub const fn determinant(&self) -> Scalar {
+        self.x_axis.perp_dot(self.y_axis)
+    }
+
+    // Applies only the linear part, as needed for directions
+    pub const fn apply_linear(&self, vector: Vector) -> Vector {
+        self.x_axis.scale(vector.x).add(self.y_axis.scale(vector.y))
+    }
+
+    pub const fn apply(&self, vector: Vector) -> Vector {
+        self.translation.apply(self.apply_linear(vector))
+    }
+
+    // The map that applies `other` first and then `self`
+    pub const fn compose(&self, other: &Self) -> Self {
+        Self::new(
+            self.apply_linear(other.x_axis),
+            self.apply_linear(other.y_axis),
+            Translation::from_vector(self.apply(other.translation.as_vector())),
+        )
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let linear = Self::new(
+            vector!(self.y_axis.y, -self.x_axis.y) / determinant,
+            vector!(-self.y_axis.x, self.x_axis.x) / determinant,
+            Translation::IDENT,
+        );
+        let translation = -linear.apply_linear(self.translation.as_vector());
+        Some(Self::new(
+            linear.x_axis,
+            linear.y_axis,
+            Translation::from_vector(translation),
+        ))
+    }
+
+    // Inverse of `from_parts`. Maps that collapse the x axis have no decomposition.
+    pub fn decompose(&self) -> Option<AffineParts> {
+        let scale_x = self.x_axis.norm();
+        if scale_x == 0.0 {
+            return None;
+        }
+
+        let x_direction = self.x_axis / scale_x;
+        let along = x_direction.dot(self.y_axis);
+        Some(AffineParts {
+            translation: self.translation.as_vector(),
+            rotation: Scalar::atan2(x_direction.y, x_direction.x),
+            scale: vector!(scale_x, x_direction.perp().dot(self.y_axis)),
+            shear: along / scale_x,
+        })
+    }
+}
+
+impl Default for Affine {
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl ApproxEq for Affine {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        approx_eq!(self.x_axis, other.x_axis, epsilon)
+            && approx_eq!(self.y_axis, other.y_axis, epsilon)
+            && approx_eq!(self.translation, other.translation, epsilon)
+    }
+}
+
+impl Mul<&Affine> for &Affine {
+    type Output = Affine;
+
+    fn mul(self, rhs: &Affine) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl MulAssign<&Affine> for Affine {
+    fn mul_assign(&mut self, rhs: &Affine) {
+        *self = &*self * rhs
+    }
+}
+
+impl Mul<Vector> for &Affine {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+impl From<Similarity> for Affine {
+    fn from(value: Similarity) -> Self {
+        Self::new(
+            value.apply_linear(Vector::X),
+            value.apply_linear(Vector::Y),
+            value.translation(),
+        )
+    }
+}
+
+impl From<ScaledRotation> for Affine {
+    fn from(value: ScaledRotation) -> Self {
+        Self::from(Similarity::from(value))
+    }
+}
+
+impl From<Translation> for Affine {
+    fn from(value: Translation) -> Self {
+        Self::new(Vector::X, Vector::Y, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{scalar, vector},
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    fn skew() -> Affine {
+        Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]])
+    }
+
+    #[test]
+    fn apply() {
+        assert_approx_eq!(&skew() * vector!(1.0, 2.0), vector!(7.0, -1.0));
+        assert_approx_eq!(skew().apply_linear(vector!(1.0, 2.0)), vector!(4.0, -2.0));
+        assert_eq!(skew().matrix(), [[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+        assert_approx_eq!(skew().determinant(), -2.0);
+    }
+
+    #[test]
+    fn compose() {
+        let a = skew();
+        let b = Affine::from_shear(0.5);
+        let v = vector!(-2.0, 3.0);
+
+        assert_approx_eq!(&(&a * &b) * v, &a * (&b * v));
+        assert_approx_eq!(&(&b * &a) * v, &b * (&a * v));
+
+        let mut c = a.clone();
+        c *= &b;
+        assert_approx_eq!(c, &a * &b);
+    }
+
+    #[test]
+    fn inverse() {
+        let a = skew();
+        let inverse = a.inverse().unwrap();
+
+        assert_approx_eq!(&inverse * &a, Affine::IDENT);
+        assert_approx_eq!(&a * &inverse, Affine::IDENT);
+
+        let flat = Affine::from_scale(vector!(1.0, 0.0));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn decompose() {
+        let parts = AffineParts {
+            translation: vector!(1.0, -2.0),
+            rotation: 0.1 * scalar::consts::TAU,
+            scale: vector!(2.0, -0.5),
+            shear: 0.75,
+        };
+        let affine = Affine::from_parts(&parts);
+        let decomposed = affine.decompose().unwrap();
+
+        assert_approx_eq!(decomposed.translation, parts.translation);
+        assert_approx_eq!(decomposed.rotation, parts.rotation);
+        assert_approx_eq!(decomposed.scale, parts.scale, 4.0 * Scalar::EPSILON);
+        assert_approx_eq!(decomposed.shear, parts.shear, 4.0 * Scalar::EPSILON);
+        assert!(affine.determinant() < 0.0);
+
+        let v = vector!(3.0, 1.0);
+        let by_steps = &Affine::from(Translation::from(parts.translation))
+            * &(&Affine::from(ScaledRotation::from_parts(1.0, parts.rotation))
+                * &(&Affine::from_scale(parts.scale) * &Affine::from_shear(parts.shear)));
+        assert_approx_eq!(&by_steps * v, &affine * v, 8.0 * Scalar::EPSILON);
+
+        assert!(Affine::from_matrix([[0.0, 1.0, 0.0], [0.0, 1.0, 0.0]])
+            .decompose()
+            .is_none());
+    }
+
+    #[test]
+    fn from_similarity() {
+        let sim = Similarity::from_parts(3.0, 0.25 * scalar::consts::TAU, vector!(2.0, 3.0));
+        let affine = Affine::from(sim.clone());
+        let v = vector!(-1.0, 4.0);
+
+        assert_approx_eq!(&affine * v, &sim * v, 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(affine.determinant(), 9.0, 8.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn from_reflection() {
+        let sim = Similarity::new_reflected(
+            ScaledRotation::from_parts(2.0, 0.7),
+            Translation::new(1.0, -1.0),
+        );
+        let affine = Affine::from(sim.clone());
+        let v = vector!(-1.0, 4.0);
+
+        assert_approx_eq!(&affine * v, &sim * v, 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(affine.determinant(), -4.0, 8.0 * Scalar::EPSILON);
+    }
+}
@@ -0,0 +1,272 @@
+use std::iter;
+
+use crate::geometry::{shape::Point, vector, Scalar, Vector};
+
+use super::{ScaledRotation, Similarity, Translation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarityFit {
+    pub similarity: Similarity,
+    // Root of the weighted mean squared distance between mapped sources and their targets
+    pub residual: Scalar,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RansacOptions {
+    pub iterations: usize,
+    // Largest distance between a mapped source and its target for the pair to count
+    pub inlier_threshold: Scalar,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RansacFit {
+    // Least squares fit over the inliers only
+    pub fit: SimilarityFit,
+    pub inliers: Vec<usize>,
+}
+
+impl Similarity {
+    // Least squares similarity taking the first point of each pair to the second, never
+    // mirroring. Needs two distinct source points.
+    pub fn fit(pairs: &[(Point, Point)]) -> Option<SimilarityFit> {
+        weighted_fit(pairs.iter().map(|&pair| (pair, 1.0)), false)
+    }
+
+    // As `fit`, but keeping the scale at 1
+    pub fn fit_rigid(pairs: &[(Point, Point)]) -> Option<SimilarityFit> {
+        weighted_fit(pairs.iter().map(|&pair| (pair, 1.0)), true)
+    }
+
+    // As `fit`, with pairs of larger weight pulled closer
+    pub fn fit_weighted(pairs: &[(Point, Point)], weights: &[Scalar]) -> Option<SimilarityFit> {
+        assert_eq!(
+            pairs.len(),
+            weights.len(),
+            "A weighted fit needs one weight per pair"
+        );
+        weighted_fit(
+            iter::zip(pairs.iter().copied(), weights.iter().copied()),
+            false,
+        )
+    }
+
+    // Fits minimal samples of two pairs and keeps the one that most pairs agree with, then
+    // refits on the pairs that agree. Robust against pairs that are simply wrong.
+    pub fn fit_ransac(pairs: &[(Point, Point)], options: &RansacOptions) -> Option<RansacFit> {
+        if pairs.len() < 2 {
+            return None;
+        }
+
+        let mut state = options.seed;
+        let mut next = |n: usize| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) % n as u64) as usize
+        };
+
+        let mut best: Option<Vec<usize>> = None;
+        for _ in 0..options.iterations {
+            let first = next(pairs.len());
+            let second = (first + 1 + next(pairs.len() - 1)) % pairs.len();
+            let Some(sample) = Self::fit(&[pairs[first], pairs[second]]) else {
+                continue;
+            };
+
+            let inliers = inliers(&sample.similarity, pairs, options.inlier_threshold);
+            if best.as_ref().is_none_or(|best| inliers.len() > best.len()) {
+                best = Some(inliers);
+            }
+        }
+
+        let inliers = best.filter(|inliers| inliers.len() >= 2)?;
+        let chosen: Vec<(Point, Point)> = inliers.iter().map(|&i| pairs[i]).collect();
+        let fit = Self::fit(&chosen)?;
+        Some(RansacFit { fit, inliers })
+    }
+}
+
+impl Default for RansacOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 100,
+            inlier_threshold: 1.0,
+            seed: 0,
+        }
+    }
+}
+
+// The scaled rotation is the complex least squares solution over the centered points, so in
+// the plane the Umeyama solution needs no singular value decomposition
+fn weighted_fit(
+    pairs: impl Iterator<Item = ((Point, Point), Scalar)> + Clone,
+    rigid: bool,
+) -> Option<SimilarityFit> {
+    let (total, source_sum, target_sum) = pairs.clone().fold(
+        (0.0, Vector::ZERO, Vector::ZERO),
+        |(total, source_sum, target_sum), ((source, target), weight)| {
+            (
+                total + weight,
+                source_sum + source.as_vector() * weight,
+                target_sum + target.as_vector() * weight,
+            )
+        },
+    );
+    if total <= 0.0 {
+        return None;
+    }
+
+    let source_center = source_sum / total;
+    let target_center = target_sum / total;
+    let (spread, correlation) = pairs.clone().fold(
+        (0.0, Vector::ZERO),
+        |(spread, correlation), ((source, target), weight)| {
+            let a = source.as_vector() - source_center;
+            let b = target.as_vector() - target_center;
+            (
+                spread + weight * a.norm_sq(),
+                // Conjugate of `a` times `b`
+                correlation + vector!(a.dot(b), a.perp_dot(b)) * weight,
+            )
+        },
+    );
+    if spread == 0.0 || (rigid && correlation == Vector::ZERO) {
+        return None;
+    }
+
+    // Only the direction of the correlation matters without a scale
+    let scaled_rotation = if rigid {
+        ScaledRotation::new(correlation / correlation.norm())
+    } else {
+        ScaledRotation::new(correlation / spread)
+    };
+    let translation = target_center - scaled_rotation.apply(source_center);
+    let similarity = Similarity::new(scaled_rotation, Translation::from_vector(translation));
+    let squared_error: Scalar = pairs
+        .map(|((source, target), weight)| weight * (&similarity * source).distance(target).powi(2))
+        .sum();
+    Some(SimilarityFit {
+        similarity,
+        residual: (squared_error / total).sqrt(),
+    })
+}
+
+fn inliers(similarity: &Similarity, pairs: &[(Point, Point)], threshold: Scalar) -> Vec<usize> {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, &(source, target))| (similarity * source).distance(target) <= threshold)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::scalar::consts::TAU, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    fn target() -> Similarity {
+        Similarity::from_parts(2.5, 0.3 * TAU, vector!(4.0, -1.0))
+    }
+
+    fn sources() -> Vec<Point> {
+        (0..12)
+            .map(|i| {
+                let i = i as Scalar;
+                Point::new(i.cos() * (1.0 + 0.3 * i), i.sin() * 2.0 - 0.1 * i)
+            })
+            .collect()
+    }
+
+    fn pairs() -> Vec<(Point, Point)> {
+        let target = target();
+        sources().into_iter().map(|p| (p, &target * p)).collect()
+    }
+
+    #[test]
+    fn fit() {
+        let fit = Similarity::fit(&pairs()).unwrap();
+
+        assert_approx_eq!(fit.similarity, target(), 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(fit.residual, 0.0, 16.0 * Scalar::EPSILON);
+
+        let single = [(Point::new(1.0, 2.0), Point::new(3.0, 4.0))];
+        assert_eq!(Similarity::fit(&single), None);
+        assert_eq!(Similarity::fit(&[]), None);
+    }
+
+    #[test]
+    fn fit_with_noise() {
+        // Pushing every target outwards by the same amount is matched by a larger scale
+        let pairs: Vec<(Point, Point)> = [(-1.0, 0.0), (1.0, 0.0), (0.0, -1.0), (0.0, 1.0)]
+            .map(|(x, y)| (Point::new(x, y), Point::new(1.1 * x, 1.1 * y)))
+            .into_iter()
+            .chain([(Point::new(0.0, 0.0), Point::new(0.0, 0.1))])
+            .collect();
+        let fit = Similarity::fit(&pairs).unwrap();
+
+        assert_approx_eq!(fit.similarity.scale(), 1.1);
+        assert_approx_eq!(fit.similarity.rotation(), 0.0);
+        assert_approx_eq!(
+            fit.similarity.translation_vector(),
+            vector!(0.0, 0.02),
+            2.0 * Scalar::EPSILON
+        );
+        assert!(fit.residual > 0.0);
+    }
+
+    #[test]
+    fn fit_rigid() {
+        let pairs = pairs();
+        let fit = Similarity::fit_rigid(&pairs).unwrap();
+
+        // The best rigid fit keeps the rotation, and the centroids still line up
+        assert_approx_eq!(fit.similarity.scale(), 1.0, 2.0 * Scalar::EPSILON);
+        assert_approx_eq!(fit.similarity.rotation(), target().rotation());
+        assert!(fit.residual > 0.0);
+
+        let moved = Similarity::from_parts(1.0, -1.0, vector!(2.0, 0.5));
+        let rigid: Vec<(Point, Point)> = sources().into_iter().map(|p| (p, &moved * p)).collect();
+        let fit = Similarity::fit_rigid(&rigid).unwrap();
+        assert_approx_eq!(fit.similarity, moved, 2.0 * Scalar::EPSILON);
+        assert_approx_eq!(fit.residual, 0.0, 8.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn fit_weighted() {
+        let mut pairs = pairs();
+        pairs.push((Point::new(0.0, 0.0), Point::new(50.0, 50.0)));
+        let mut weights = vec![1.0; pairs.len()];
+
+        let skewed = Similarity::fit_weighted(&pairs, &weights).unwrap();
+        assert!(skewed.residual > 1.0);
+
+        // Ignoring the wrong pair recovers the exact transform
+        *weights.last_mut().unwrap() = 0.0;
+        let fit = Similarity::fit_weighted(&pairs, &weights).unwrap();
+        assert_approx_eq!(fit.similarity, target(), 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(fit.residual, 0.0, 16.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn fit_ransac() {
+        let mut pairs = pairs();
+        // Every third pair is matched to the wrong target
+        for i in (0..pairs.len()).step_by(3) {
+            pairs[i].1 += vector!(3.0, -7.0) * (i as Scalar + 1.0);
+        }
+        let options = RansacOptions {
+            inlier_threshold: 0.01,
+            ..RansacOptions::default()
+        };
+
+        let ransac = Similarity::fit_ransac(&pairs, &options).unwrap();
+        assert_eq!(ransac.inliers, vec![1, 2, 4, 5, 7, 8, 10, 11]);
+        assert_approx_eq!(ransac.fit.similarity, target(), 4.0 * Scalar::EPSILON);
+        assert!(Similarity::fit(&pairs).unwrap().residual > 1.0);
+
+        assert_eq!(Similarity::fit_ransac(&pairs[..1], &options), None);
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    iter,
+    ops::{Mul, MulAssign},
+};
+
+use crate::{
+    geometry::{vector, Scalar, Vector},
+    utils::approx::ApproxEq,
+};
+
+use super::{Affine, Similarity};
+
+// Point in homogeneous coordinates, standing for (x / w, y / w). A zero `w` is a point at
+// infinity in the direction (x, y).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HomogeneousPoint {
+    pub x: Scalar,
+    pub y: Scalar,
+    pub w: Scalar,
+}
+
+// Projective map of the plane, stored as a 3x3 matrix acting on homogeneous coordinates.
+// Scaling the whole matrix gives the same map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Homography {
+    matrix: [[Scalar; 3]; 3],
+}
+
+impl HomogeneousPoint {
+    pub const fn new(x: Scalar, y: Scalar, w: Scalar) -> Self {
+        Self { x, y, w }
+    }
+
+    pub const fn from_vector(vector: Vector) -> Self {
+        Self::new(vector.x, vector.y, 1.0)
+    }
+
+    pub const fn at_infinity(direction: Vector) -> Self {
+        Self::new(direction.x, direction.y, 0.0)
+    }
+
+    pub fn is_at_infinity(&self) -> bool {
+        self.w == 0.0
+    }
+
+    pub fn to_vector(self) -> Option<Vector> {
+        (!self.is_at_infinity()).then(|| vector!(self.x / self.w, self.y / self.w))
+    }
+}
+
+impl Homography {
+    pub const IDENT: Self = Self::new([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    // Rows of the matrix [a b c; d e f; g h i], which maps (x, y) to
+    // ((a x + b y + c) / w, (d x + e y + f) / w) with w = g x + h y + i
+    pub const fn new(matrix: [[Scalar; 3]; 3]) -> Self {
+        Self { matrix }
+    }
+
+    // The map taking the corners (0, 0), (1, 0), (1, 1) and (0, 1) of the unit square to
+    // the corners of `quad` in order. Quads with three corners on a line have none.
+    pub fn from_unit_square(quad: [Vector; 4]) -> Option<Self> {
+        let [p0, p1, p2, p3] = quad;
+        let sum = p0 - p1 + p2 - p3;
+        let d1 = p1 - p2;
+        let d2 = p3 - p2;
+        let denominator = d1.perp_dot(d2);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        let g = sum.perp_dot(d2) / denominator;
+        let h = d1.perp_dot(sum) / denominator;
+        let x_axis = p1 * (1.0 + g) - p0;
+        let y_axis = p3 * (1.0 + h) - p0;
+        let homography = Self::new([
+            [x_axis.x, y_axis.x, p0.x],
+            [x_axis.y, y_axis.y, p0.y],
+            [g, h, 1.0],
+        ]);
+        (homography.determinant() != 0.0).then_some(homography)
+    }
+
+    // The map taking the first point of each pair to the second
+    pub fn from_correspondences(pairs: [(Vector, Vector); 4]) -> Option<Self> {
+        let from = Self::from_unit_square(pairs.map(|(from, _)| from))?;
+        let to = Self::from_unit_square(pairs.map(|(_, to)| to))?;
+        Some(to.compose(&from.inverse()?))
+    }
+
+    pub const fn matrix(&self) -> [[Scalar; 3]; 3] {
+        self.matrix
+    }
+
+    pub fn determinant(&self) -> Scalar {
+        let [[a, b, c], [d, e, f], [g, h, i]] = self.matrix;
+        a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+    }
+
+    pub fn apply_homogeneous(&self, point: HomogeneousPoint) -> HomogeneousPoint {
+        let [r0, r1, r2] = self
+            .matrix
+            .map(|[a, b, c]| a * point.x + b * point.y + c * point.w);
+        HomogeneousPoint::new(r0, r1, r2)
+    }
+
+    // None when the vector is sent to infinity
+    pub fn apply(&self, vector: Vector) -> Option<Vector> {
+        self.apply_homogeneous(HomogeneousPoint::from_vector(vector))
+            .to_vector()
+    }
+
+    // The map that applies `other` first and then `self`
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut matrix = [[0.0; 3]; 3];
+        for (row, lhs) in matrix.iter_mut().zip(&self.matrix) {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| lhs[k] * other.matrix[k][j]).sum();
+            }
+        }
+        Self::new(matrix)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+        if determinant == 0.0 {
+            return None;
+        }
+
+        // Adjugate over determinant, with the cofactors read off cyclically
+        let m = self.matrix;
+        let mut matrix = [[0.0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+                *value = (m[j1][i1] * m[j2][i2] - m[j1][i2] * m[j2][i1]) / determinant;
+            }
+        }
+        Some(Self::new(matrix))
+    }
+
+    // Scaled so that the entry of largest magnitude is 1
+    fn normalized(&self) -> [[Scalar; 3]; 3] {
+        let largest = self
+            .matrix
+            .iter()
+            .flatten()
+            .copied()
+            .fold(0.0, |largest: Scalar, value| {
+                if value.abs() > largest.abs() {
+                    value
+                } else {
+                    largest
+                }
+            });
+        if largest == 0.0 {
+            return self.matrix;
+        }
+        self.matrix.map(|row| row.map(|value| value / largest))
+    }
+}
+
+impl From<Vector> for HomogeneousPoint {
+    fn from(value: Vector) -> Self {
+        Self::from_vector(value)
+    }
+}
+
+impl Default for Homography {
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl ApproxEq for Homography {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    // Homographies that differ only by a factor are the same map
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        iter::zip(
+            self.normalized().iter().flatten(),
+            other.normalized().iter().flatten(),
+        )
+        .all(|(a, b)| a.approx_eq(b, epsilon))
+    }
+}
+
+impl Mul<&Homography> for &Homography {
+    type Output = Homography;
+
+    fn mul(self, rhs: &Homography) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl MulAssign<&Homography> for Homography {
+    fn mul_assign(&mut self, rhs: &Homography) {
+        *self = &*self * rhs
+    }
+}
+
+impl Mul<HomogeneousPoint> for &Homography {
+    type Output = HomogeneousPoint;
+
+    fn mul(self, rhs: HomogeneousPoint) -> Self::Output {
+        self.apply_homogeneous(rhs)
+    }
+}
+
+impl From<Affine> for Homography {
+    fn from(value: Affine) -> Self {
+        let [r0, r1] = value.matrix();
+        Self::new([r0, r1, [0.0, 0.0, 1.0]])
+    }
+}
+
+impl From<Similarity> for Homography {
+    fn from(value: Similarity) -> Self {
+        Self::from(Affine::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::vector, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    // Camera looking down at the ground at an angle, so the far side of the square shrinks
+    fn camera() -> Homography {
+        Homography::from_correspondences([
+            (vector!(0.0, 0.0), vector!(-1.0, 0.0)),
+            (vector!(4.0, 0.0), vector!(5.0, 0.0)),
+            (vector!(4.0, 4.0), vector!(3.0, 2.0)),
+            (vector!(0.0, 4.0), vector!(1.0, 2.0)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn from_correspondences() {
+        let camera = camera();
+
+        for (from, to) in [
+            (vector!(0.0, 0.0), vector!(-1.0, 0.0)),
+            (vector!(4.0, 0.0), vector!(5.0, 0.0)),
+            (vector!(4.0, 4.0), vector!(3.0, 2.0)),
+            (vector!(0.0, 4.0), vector!(1.0, 2.0)),
+        ] {
+            assert_approx_eq!(camera.apply(from).unwrap(), to);
+        }
+        // The middle of the square is where the diagonals of the image cross
+        assert_approx_eq!(camera.apply(vector!(2.0, 2.0)).unwrap(), vector!(2.0, 1.5));
+
+        let collinear = [
+            (vector!(0.0, 0.0), vector!(0.0, 0.0)),
+            (vector!(1.0, 0.0), vector!(1.0, 0.0)),
+            (vector!(2.0, 0.0), vector!(1.0, 1.0)),
+            (vector!(0.0, 1.0), vector!(0.0, 1.0)),
+        ];
+        assert_eq!(Homography::from_correspondences(collinear), None);
+    }
+
+    #[test]
+    fn compose() {
+        let a = camera();
+        let b = Homography::from(Similarity::from_parts(2.0, 0.3, vector!(1.0, -1.0)));
+        let v = vector!(1.5, 0.5);
+
+        assert_approx_eq!(
+            (&a * &b).apply(v).unwrap(),
+            a.apply(b.apply(v).unwrap()).unwrap(),
+            4.0 * Scalar::EPSILON
+        );
+
+        let mut c = a.clone();
+        c *= &b;
+        assert_approx_eq!(c, &a * &b);
+    }
+
+    #[test]
+    fn inverse() {
+        let camera = camera();
+        let inverse = camera.inverse().unwrap();
+
+        assert_approx_eq!(&camera * &inverse, Homography::IDENT, 2.0 * Scalar::EPSILON);
+        assert_approx_eq!(
+            inverse.apply(vector!(3.0, 2.0)).unwrap(),
+            vector!(4.0, 4.0),
+            8.0 * Scalar::EPSILON
+        );
+        assert_eq!(
+            Homography::new([[1.0, 2.0, 0.0], [2.0, 4.0, 0.0], [0.0, 0.0, 1.0]]).inverse(),
+            None
+        );
+    }
+
+    #[test]
+    fn points_at_infinity() {
+        let camera = camera();
+
+        // Parallel lines along y on the ground meet at the vanishing point
+        let vanishing = camera
+            .apply_homogeneous(HomogeneousPoint::at_infinity(Vector::Y))
+            .to_vector()
+            .unwrap();
+        assert_approx_eq!(vanishing, vector!(2.0, 3.0));
+
+        // and the horizon of the image comes from points at infinity of the ground
+        let horizon = camera.inverse().unwrap();
+        let sent_away = horizon.apply_homogeneous(HomogeneousPoint::from_vector(vector!(7.0, 3.0)));
+        assert!(sent_away.is_at_infinity());
+        assert_eq!(horizon.apply(vector!(7.0, 3.0)), None);
+    }
+
+    #[test]
+    fn scale_invariant() {
+        let camera = camera();
+        let scaled = Homography::new(camera.matrix().map(|row| row.map(|v| -3.0 * v)));
+
+        assert_approx_eq!(scaled, camera);
+        assert_approx_eq!(
+            scaled.apply(vector!(1.0, 3.0)).unwrap(),
+            camera.apply(vector!(1.0, 3.0)).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_affine() {
+        let affine = Affine::from_matrix([[2.0, 1.0, 3.0], [0.0, -1.0, 1.0]]);
+        let homography = Homography::from(affine.clone());
+        let v = vector!(1.0, 2.0);
+
+        assert_approx_eq!(homography.apply(v).unwrap(), &affine * v);
+        assert_approx_eq!(homography.determinant(), affine.determinant());
+    }
+}
@@ -0,0 +1,260 @@
+use std::ops::{Mul, MulAssign};
+
+use crate::{
+    geometry::{vector, Scalar, Vector},
+    utils::approx::{approx_eq, ApproxEq},
+};
+
+use super::{ScaledRotation, Similarity, Translation};
+
+// Rotation followed by a translation, which keeps all distances. The rotation is stored as a
+// unit complex number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isometry {
+    rotation: ScaledRotation,
+    translation: Translation,
+}
+
+impl Isometry {
+    pub const IDENT: Self = Self::new(ScaledRotation::IDENT, Translation::IDENT);
+
+    // `rotation` must be of unit scale
+    pub const fn new(rotation: ScaledRotation, translation: Translation) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn from_parts(rotation: Scalar, translation_vector: Vector) -> Self {
+        Self::new(
+            ScaledRotation::from_parts(1.0, rotation),
+            Translation::from(translation_vector),
+        )
+    }
+
+    pub fn rotation(&self) -> Scalar {
+        self.rotation.rotation()
+    }
+
+    // Always of unit scale
+    pub const fn scaled_rotation(&self) -> ScaledRotation {
+        self.rotation
+    }
+
+    pub const fn translation(&self) -> Translation {
+        self.translation
+    }
+
+    pub const fn translation_vector(&self) -> Vector {
+        self.translation.as_vector()
+    }
+
+    pub const fn apply(&self, vector: Vector) -> Vector {
+        self.translation.apply(self.rotation.apply(vector))
+    }
+
+    // The isometry that applies `other` first and then `self`
+    pub fn compose(&self, other: &Self) -> Self {
+        // Renormalizing keeps rounding errors from building up into a scale
+        let rotation = self.rotation.compose(other.rotation);
+        let norm = vector!(rotation.re(), rotation.im()).norm();
+        Self::new(
+            ScaledRotation::new(vector!(rotation.re() / norm, rotation.im() / norm)),
+            Translation::from_vector(self.apply(other.translation_vector())),
+        )
+    }
+
+    pub const fn inverse(&self) -> Self {
+        // The conjugate is the exact inverse of a unit complex number
+        let rotation = ScaledRotation::new(vector!(self.rotation.re(), -self.rotation.im()));
+        Self::new(
+            rotation,
+            Translation::from_vector(rotation.apply(self.translation.inverse().as_vector())),
+        )
+    }
+
+    // Moves from `self` at `t = 0` to `other` at `t = 1`, turning along the shorter arc and
+    // translating in a straight line
+    pub fn interpolate(&self, other: &Self, t: Scalar) -> Self {
+        Self::new(
+            self.rotation.slerp(other.rotation, t),
+            self.translation.lerp(other.translation, t),
+        )
+    }
+}
+
+impl Default for Isometry {
+    fn default() -> Self {
+        Self::IDENT
+    }
+}
+
+impl ApproxEq for Isometry {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        approx_eq!(self.rotation, other.rotation, epsilon)
+            && approx_eq!(self.translation, other.translation, epsilon)
+    }
+}
+
+impl Mul<&Isometry> for &Isometry {
+    type Output = Isometry;
+
+    fn mul(self, rhs: &Isometry) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl MulAssign<&Isometry> for Isometry {
+    fn mul_assign(&mut self, rhs: &Isometry) {
+        *self = &*self * rhs
+    }
+}
+
+impl Mul<Vector> for &Isometry {
+    type Output = Vector;
+
+    fn mul(self, rhs: Vector) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+impl From<Translation> for Isometry {
+    fn from(value: Translation) -> Self {
+        Self::new(ScaledRotation::IDENT, value)
+    }
+}
+
+impl From<Isometry> for Similarity {
+    fn from(value: Isometry) -> Self {
+        Self::new(value.rotation, value.translation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geometry::{
+            scalar::consts::TAU,
+            shape::{Point, Segment, Shape},
+            vector,
+        },
+        utils::approx::assert_approx_eq,
+    };
+
+    use super::*;
+
+    #[test]
+    fn apply() {
+        let iso = Isometry::from_parts(0.25 * TAU, vector!(2.0, 3.0));
+
+        assert_approx_eq!(&iso * Vector::Y, vector!(1.0, 3.0));
+        assert_approx_eq!(
+            (&iso * vector!(3.0, 4.0) - iso.translation_vector()).norm(),
+            5.0
+        );
+    }
+
+    #[test]
+    fn compose() {
+        let iso_1 = Isometry::from_parts(0.25 * TAU, vector!(2.0, 3.0));
+        let iso_2 = Isometry::from_parts(-0.5 * TAU, vector!(-1.0, 1.0));
+        let v = vector!(-2.0, 5.0);
+
+        assert_approx_eq!(
+            &(&iso_1 * &iso_2) * v,
+            &iso_1 * (&iso_2 * v),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            &iso_1 * &iso_2,
+            Isometry::from_parts(-0.25 * TAU, vector!(1.0, 2.0)),
+            4.0 * Scalar::EPSILON
+        );
+
+        let mut iso = iso_1.clone();
+        iso *= &iso_2;
+        assert_approx_eq!(iso, &iso_1 * &iso_2);
+    }
+
+    #[test]
+    fn inverse() {
+        let iso = Isometry::from_parts(0.1 * TAU, vector!(2.0, -3.0));
+
+        assert_approx_eq!(
+            &iso * &iso.inverse(),
+            Isometry::IDENT,
+            4.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            &iso.inverse() * &iso,
+            Isometry::IDENT,
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn repeated_compose_keeps_unit_scale() {
+        let step = Isometry::from_parts(0.001, vector!(0.01, 0.0));
+        let mut iso = Isometry::IDENT;
+        for _ in 0..10000 {
+            iso *= &step;
+        }
+
+        assert_approx_eq!(iso.scaled_rotation().scale(), 1.0);
+        assert_approx_eq!(iso.rotation(), 10.0 - 2.0 * TAU, 64.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn interpolate() {
+        let a = Isometry::from_parts(0.45 * TAU, vector!(0.0, 0.0));
+        let b = Isometry::from_parts(-0.45 * TAU, vector!(2.0, 4.0));
+
+        // The shorter arc crosses the half turn
+        let halfway = a.interpolate(&b, 0.5);
+        assert_approx_eq!(
+            halfway,
+            Isometry::from_parts(0.5 * TAU, vector!(1.0, 2.0)),
+            4.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(a.interpolate(&b, 0.0), a);
+        assert_approx_eq!(a.interpolate(&b, 1.0), b, 4.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn new() {
+        let iso = Isometry::new(
+            ScaledRotation::from_parts(1.0, 0.25 * TAU),
+            Translation::from(vector!(2.0, 3.0)),
+        );
+
+        assert_eq!(iso, Isometry::from_parts(0.25 * TAU, vector!(2.0, 3.0)));
+    }
+
+    #[test]
+    fn apply_to_point_and_shape() {
+        let iso = Isometry::from_parts(0.1 * TAU, vector!(2.0, -3.0));
+        let sim = Similarity::from(iso.clone());
+        let p = Point::new(-2.0, 5.0);
+        let segment = Segment::new(p, Point::new(1.0, 1.0));
+
+        assert_eq!(&iso * p, &sim * p);
+        assert_eq!(
+            &iso * Shape::from(segment.clone()),
+            Shape::from(segment) * &sim
+        );
+    }
+
+    #[test]
+    fn into_similarity() {
+        let iso = Isometry::from_parts(0.1 * TAU, vector!(2.0, -3.0));
+        let sim = Similarity::from(iso.clone());
+        let v = vector!(-2.0, 5.0);
+
+        assert_eq!(&sim * v, &iso * v);
+        assert_eq!(sim.scaled_rotation(), iso.scaled_rotation());
+        assert_eq!(sim.translation(), iso.translation());
+    }
+}
@@ -1,7 +1,17 @@
+mod affine;
+mod fit;
+mod homography;
+mod isometry;
 mod scaled_rotation;
 mod similarity;
 mod translation;
+mod twist;
 
+pub use affine::*;
+pub use fit::*;
+pub use homography::*;
+pub use isometry::*;
 pub use scaled_rotation::*;
 pub use similarity::*;
 pub use translation::*;
+pub use twist::*;
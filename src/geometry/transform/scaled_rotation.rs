@@ -42,6 +42,24 @@ impl ScaledRotation {
     pub const fn apply(self, vector: Vector) -> Vector {
         self.0.complex_product(vector)
     }
+
+    // Turns along the shorter arc while the scale changes by a constant factor per unit of
+    // `t`. Neither end may have zero scale.
+    pub fn slerp(self, other: ScaledRotation, t: Scalar) -> ScaledRotation {
+        let angle = Scalar::atan2(self.0.perp_dot(other.0), self.0.dot(other.0));
+        let ratio = other.scale() / self.scale();
+        self.compose(Self::from_parts(ratio.powf(t), angle * t))
+    }
+
+    // Same scale, opposite rotation
+    pub const fn conjugate(self) -> ScaledRotation {
+        Self::new(vector!(self.re(), -self.im()))
+    }
+
+    pub const fn inverse(self) -> ScaledRotation {
+        let norm_sq = self.0.norm_sq();
+        Self::new(vector!(self.re() / norm_sq, -self.im() / norm_sq))
+    }
 }
 
 impl Default for ScaledRotation {
@@ -126,6 +144,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inverse() {
+        let t = ScaledRotation::from_parts(2.0, 0.125 * scalar::consts::TAU);
+
+        assert_approx_eq!(
+            t.inverse(),
+            ScaledRotation::from_parts(0.5, -0.125 * scalar::consts::TAU)
+        );
+        assert_approx_eq!(t * t.inverse(), ScaledRotation::IDENT);
+    }
+
     #[test]
     fn extract_parts() {
         let t = ScaledRotation::from_parts(2.0, 0.25 * scalar::consts::TAU);
@@ -133,4 +162,24 @@ mod tests {
         assert_approx_eq!(t.scale(), 2.0);
         assert_approx_eq!(t.rotation(), 0.25 * scalar::consts::TAU);
     }
+
+    #[test]
+    fn slerp() {
+        let from = ScaledRotation::from_parts(1.0, 0.45 * scalar::consts::TAU);
+        let to = ScaledRotation::from_parts(4.0, -0.45 * scalar::consts::TAU);
+
+        assert_approx_eq!(from.slerp(to, 0.0), from);
+        assert_approx_eq!(from.slerp(to, 1.0), to, 8.0 * Scalar::EPSILON);
+        // The shorter arc crosses the half turn, and the scale grows geometrically
+        assert_approx_eq!(
+            from.slerp(to, 0.5),
+            ScaledRotation::from_parts(2.0, 0.5 * scalar::consts::TAU),
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            from.slerp(to, 0.25).scale(),
+            Scalar::sqrt(2.0),
+            4.0 * Scalar::EPSILON
+        );
+    }
 }
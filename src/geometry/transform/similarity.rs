@@ -1,16 +1,19 @@
 use std::ops::{Mul, MulAssign};
 
 use crate::{
-    geometry::{Scalar, Vector},
+    geometry::{vector, Scalar, Vector},
     utils::approx::{approx_eq, ApproxEq},
 };
 
-use super::{ScaledRotation, Translation};
+use super::{ScaledRotation, SimilarityTwist, Translation};
 
+// A reflecting similarity mirrors across the x axis before the scaled rotation, which
+// reverses the orientation of everything it maps
 #[derive(Debug, Clone, PartialEq)]
 pub struct Similarity {
     scaled_rotation: ScaledRotation,
     translation: Translation,
+    reflects: bool,
 }
 
 impl Similarity {
@@ -20,6 +23,15 @@ impl Similarity {
         Self {
             scaled_rotation,
             translation,
+            reflects: false,
+        }
+    }
+
+    pub const fn new_reflected(scaled_rotation: ScaledRotation, translation: Translation) -> Self {
+        Self {
+            scaled_rotation,
+            translation,
+            reflects: true,
         }
     }
 
@@ -30,6 +42,18 @@ impl Similarity {
         )
     }
 
+    // Mirrors across the line through the origin at `axis_angle`
+    pub fn from_reflection(axis_angle: Scalar) -> Self {
+        Self::new_reflected(
+            ScaledRotation::from_parts(1.0, 2.0 * axis_angle),
+            Translation::IDENT,
+        )
+    }
+
+    pub const fn reflects(&self) -> bool {
+        self.reflects
+    }
+
     pub const fn scaled_rotation(&self) -> ScaledRotation {
         self.scaled_rotation
     }
@@ -50,15 +74,122 @@ impl Similarity {
         self.translation.as_vector()
     }
 
+    // The similarity that applies `other` first and then `self`
     pub const fn compose(&self, other: &Self) -> Self {
+        // Mirroring conjugates whatever rotation happens before it
+        let other_rotation = if self.reflects {
+            other.scaled_rotation.conjugate()
+        } else {
+            other.scaled_rotation
+        };
+        Self {
+            scaled_rotation: self.scaled_rotation.compose(other_rotation),
+            translation: Translation::from_vector(self.apply(other.translation.as_vector())),
+            reflects: self.reflects != other.reflects,
+        }
+    }
+
+    // Applies only the mirroring and the scaled rotation, as needed for directions
+    pub const fn apply_linear(&self, vector: Vector) -> Vector {
+        let vector = if self.reflects {
+            vector!(vector.x, -vector.y)
+        } else {
+            vector
+        };
+        self.scaled_rotation.apply(vector)
+    }
+
+    pub const fn apply(&self, vector: Vector) -> Vector {
+        self.translation.apply(self.apply_linear(vector))
+    }
+
+    // The similarity reached from the identity by holding `twist` for unit time
+    pub fn exp(twist: &SimilarityTwist) -> Self {
+        let exponent = vector!(twist.log_scale, twist.angular);
         Self::new(
-            self.scaled_rotation.compose(other.scaled_rotation),
-            self.translation.compose(other.translation),
+            ScaledRotation::new(complex_exp(exponent)),
+            Translation::from_vector(translation_factor(exponent).complex_product(twist.linear)),
         )
     }
 
-    pub const fn apply(&self, vector: Vector) -> Vector {
-        self.translation.apply(self.scaled_rotation.apply(vector))
+    // Inverse of `exp`, with the angle in (-pi, pi]. Mirroring similarities have none.
+    pub fn log(&self) -> Option<SimilarityTwist> {
+        if self.reflects {
+            return None;
+        }
+
+        let exponent = vector!(self.scale().ln(), self.rotation());
+        let linear = complex_quotient(self.translation_vector(), translation_factor(exponent));
+        Some(SimilarityTwist::new(exponent.y, exponent.x, linear))
+    }
+
+    // Carries a twist from the frame of `self` into the outer frame, so that following
+    // `self.adjoint(twist)` matches undoing `self`, following `twist` and redoing `self`
+    pub fn adjoint(&self, twist: &SimilarityTwist) -> SimilarityTwist {
+        // Mirroring reverses the sense of rotation but not the growth
+        let angular = if self.reflects {
+            -twist.angular
+        } else {
+            twist.angular
+        };
+        let exponent = vector!(twist.log_scale, angular);
+        let linear =
+            self.apply_linear(twist.linear) - exponent.complex_product(self.translation_vector());
+        SimilarityTwist::new(angular, twist.log_scale, linear)
+    }
+
+    // Moves from `self` at `t = 0` to `other` at `t = 1` as `ScaledRotation::slerp` and
+    // `Translation::lerp` do. None unless both agree on mirroring, since no path of
+    // similarities joins a mirrored one to an unmirrored one.
+    pub fn interpolate(&self, other: &Self, t: Scalar) -> Option<Self> {
+        if self.reflects != other.reflects {
+            return None;
+        }
+        Some(Self {
+            scaled_rotation: self.scaled_rotation.slerp(other.scaled_rotation, t),
+            translation: self.translation.lerp(other.translation, t),
+            reflects: self.reflects,
+        })
+    }
+
+    pub const fn inverse(&self) -> Self {
+        let scaled_rotation = if self.reflects {
+            self.scaled_rotation.inverse().conjugate()
+        } else {
+            self.scaled_rotation.inverse()
+        };
+        let linear = Self {
+            scaled_rotation,
+            translation: Translation::IDENT,
+            reflects: self.reflects,
+        };
+        Self {
+            translation: Translation::from_vector(
+                linear.apply_linear(self.translation.inverse().as_vector()),
+            ),
+            ..linear
+        }
+    }
+}
+
+// Vectors standing in for complex numbers, as in `ScaledRotation`
+fn complex_exp(z: Vector) -> Vector {
+    let (sin, cos) = z.y.sin_cos();
+    vector!(cos, sin) * z.x.exp()
+}
+
+fn complex_quotient(a: Vector, b: Vector) -> Vector {
+    vector!(a.x * b.x + a.y * b.y, a.y * b.x - a.x * b.y) / b.norm_sq()
+}
+
+// (e^z - 1) / z, which turns a linear velocity into the translation it adds up to
+fn translation_factor(z: Vector) -> Vector {
+    if z.norm_sq() < Scalar::EPSILON.sqrt() {
+        // Taylor series, as the quotient loses all precision near zero
+        let z_sq = z.complex_product(z);
+        Vector::X + z / 2.0 + z_sq / 6.0 + z_sq.complex_product(z) / 24.0
+    } else {
+        complex_quotient(complex_exp(z) - Vector::X, z)
     }
 }
 
@@ -72,7 +203,8 @@ impl ApproxEq for Similarity {
     type Epsilon = <Scalar as ApproxEq>::Epsilon;
 
     fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        approx_eq!(self.scaled_rotation, other.scaled_rotation, epsilon)
+        self.reflects == other.reflects
+            && approx_eq!(self.scaled_rotation, other.scaled_rotation, epsilon)
             && approx_eq!(self.translation, other.translation, epsilon)
     }
 }
@@ -133,14 +265,42 @@ mod tests {
         assert_approx_eq!(&sim * Vector::Y, vector!(-1.0, 3.0), 2.0 * Scalar::EPSILON);
     }
 
+    #[test]
+    fn inverse() {
+        let sim = Similarity::from_parts(3.0, 0.25 * scalar::consts::TAU, vector!(2.0, 3.0));
+        let inverse = sim.inverse();
+
+        assert_approx_eq!(
+            inverse,
+            Similarity::from_parts(
+                1.0 / 3.0,
+                -0.25 * scalar::consts::TAU,
+                vector!(-1.0, 2.0 / 3.0)
+            ),
+            2.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            &inverse * (&sim * vector!(-4.0, 5.0)),
+            vector!(-4.0, 5.0),
+            8.0 * Scalar::EPSILON
+        );
+    }
+
     #[test]
     fn compose() {
         let sim_1 = Similarity::from_parts(3.0, 0.25 * scalar::consts::TAU, vector!(2.0, 3.0));
         let sim_2 = Similarity::from_parts(2.0, -0.5 * scalar::consts::TAU, vector!(-1.0, 1.0));
+        let v = vector!(-2.0, 5.0);
 
+        assert_approx_eq!(
+            &(&sim_1 * &sim_2) * v,
+            &sim_1 * (&sim_2 * v),
+            16.0 * Scalar::EPSILON
+        );
         assert_approx_eq!(
             &sim_1 * &sim_2,
-            Similarity::from_parts(6.0, -0.25 * scalar::consts::TAU, vector!(1.0, 4.0))
+            Similarity::from_parts(6.0, -0.25 * scalar::consts::TAU, vector!(-1.0, 0.0)),
+            4.0 * Scalar::EPSILON
         );
     }
 
@@ -151,7 +311,169 @@ mod tests {
 
         assert_approx_eq!(
             sim,
-            Similarity::from_parts(6.0, -0.25 * scalar::consts::TAU, vector!(1.0, 4.0))
+            Similarity::from_parts(6.0, -0.25 * scalar::consts::TAU, vector!(-1.0, 0.0)),
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn reflection() {
+        let mirror = Similarity::from_reflection(0.25 * scalar::consts::TAU);
+
+        assert!(mirror.reflects());
+        assert_approx_eq!(
+            &mirror * vector!(2.0, 3.0),
+            vector!(-2.0, 3.0),
+            4.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            mirror.apply_linear(Vector::Y),
+            Vector::Y,
+            2.0 * Scalar::EPSILON
+        );
+
+        let twice = &mirror * &mirror;
+        assert!(!twice.reflects());
+        assert_approx_eq!(twice, Similarity::IDENT, 4.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn reflected_inverse() {
+        let sim = Similarity::new_reflected(
+            ScaledRotation::from_parts(2.0, 1.0),
+            Translation::new(3.0, -1.0),
+        );
+        let inverse = sim.inverse();
+        let v = vector!(-4.0, 5.0);
+
+        assert!(inverse.reflects());
+        assert_approx_eq!(&inverse * (&sim * v), v, 8.0 * Scalar::EPSILON);
+        assert_approx_eq!(&sim * (&inverse * v), v, 8.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn compose_with_reflection() {
+        let rotation = Similarity::from_parts(3.0, 0.1 * scalar::consts::TAU, vector!(2.0, -1.0));
+        let mirror = Similarity::new_reflected(
+            ScaledRotation::from_parts(0.5, 0.6),
+            Translation::new(-3.0, 4.0),
+        );
+        let v = vector!(-2.0, 5.0);
+
+        for (a, b) in [
+            (&rotation, &mirror),
+            (&mirror, &rotation),
+            (&mirror, &mirror),
+        ] {
+            assert_eq!((a * b).reflects(), a.reflects() != b.reflects());
+            assert_approx_eq!(&(a * b) * v, a * (b * v), 16.0 * Scalar::EPSILON);
+        }
+    }
+
+    #[test]
+    fn interpolate() {
+        let from = Similarity::from_parts(0.5, 0.1 * scalar::consts::TAU, vector!(0.0, 2.0));
+        let to = Similarity::from_parts(2.0, 0.9 * scalar::consts::TAU, vector!(4.0, -2.0));
+
+        assert_approx_eq!(from.interpolate(&to, 0.0).unwrap(), from);
+        assert_approx_eq!(
+            from.interpolate(&to, 1.0).unwrap(),
+            to,
+            8.0 * Scalar::EPSILON
+        );
+        assert_approx_eq!(
+            from.interpolate(&to, 0.5).unwrap(),
+            Similarity::from_parts(1.0, 0.0, vector!(2.0, 0.0)),
+            8.0 * Scalar::EPSILON
+        );
+
+        let mirrored = &Similarity::from_reflection(0.3) * &to;
+        let halfway = (&Similarity::from_reflection(0.3) * &from)
+            .interpolate(&mirrored, 0.5)
+            .unwrap();
+        assert!(halfway.reflects());
+        assert_approx_eq!(halfway.scale(), 1.0, 8.0 * Scalar::EPSILON);
+    }
+
+    #[test]
+    fn interpolate_mirrored() {
+        assert_eq!(
+            Similarity::IDENT.interpolate(&Similarity::from_reflection(0.0), 0.5),
+            None
+        );
+    }
+
+    #[test]
+    fn exp() {
+        assert_approx_eq!(Similarity::exp(&SimilarityTwist::ZERO), Similarity::IDENT);
+        assert_approx_eq!(
+            Similarity::exp(&SimilarityTwist::new(0.0, 0.0, vector!(2.0, -1.0))),
+            Similarity::from(Translation::new(2.0, -1.0))
+        );
+
+        // A quarter turn at unit speed along a circle of radius 2 / pi
+        let turn = SimilarityTwist::new(0.25 * scalar::consts::TAU, 0.0, Vector::X);
+        let radius = 2.0 / scalar::consts::PI;
+        assert_approx_eq!(
+            Similarity::exp(&turn),
+            Similarity::from_parts(1.0, 0.25 * scalar::consts::TAU, vector!(radius, radius)),
+            4.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn exp_integrates() {
+        let twist = SimilarityTwist::new(1.3, -0.4, vector!(0.5, 2.0));
+        let half = Similarity::exp(&(twist * 0.5));
+        let v = vector!(1.0, -3.0);
+
+        assert_approx_eq!(
+            &half * (&half * v),
+            &Similarity::exp(&twist) * v,
+            16.0 * Scalar::EPSILON
+        );
+    }
+
+    #[test]
+    fn log() {
+        for twist in [
+            SimilarityTwist::new(1.3, -0.4, vector!(0.5, 2.0)),
+            SimilarityTwist::new(-3.0, 0.7, vector!(-1.0, 0.0)),
+            SimilarityTwist::new(1e-7, -1e-6, vector!(3.0, 1.0)),
+            SimilarityTwist::new(0.0, 0.0, vector!(3.0, 1.0)),
+        ] {
+            let log = Similarity::exp(&twist).log().unwrap();
+            assert_approx_eq!(log, twist, 8.0 * Scalar::EPSILON);
+        }
+
+        let sim = Similarity::from_parts(3.0, 0.25 * scalar::consts::TAU, vector!(2.0, 3.0));
+        assert_approx_eq!(
+            Similarity::exp(&sim.log().unwrap()),
+            sim,
+            16.0 * Scalar::EPSILON
         );
+        assert_eq!(Similarity::from_reflection(0.0).log(), None);
+    }
+
+    #[test]
+    fn adjoint() {
+        let twist = SimilarityTwist::new(1.3, -0.4, vector!(0.5, 2.0));
+        let moved = Similarity::exp(&twist);
+        let v = vector!(1.0, -3.0);
+
+        for frame in [
+            Similarity::from_parts(3.0, 0.25 * scalar::consts::TAU, vector!(2.0, 3.0)),
+            Similarity::new_reflected(
+                ScaledRotation::from_parts(0.5, 1.0),
+                Translation::new(-1.0, 4.0),
+            ),
+        ] {
+            let outer = Similarity::exp(&frame.adjoint(&twist));
+            assert_approx_eq!(
+                &outer * v,
+                &frame * (&moved * (&frame.inverse() * v)),
+                16.0 * Scalar::EPSILON
+            );
+        }
     }
 }
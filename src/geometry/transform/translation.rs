@@ -30,6 +30,14 @@ impl Translation {
     pub const fn apply(self, vector: Vector) -> Vector {
         vector.add(self.0)
     }
+
+    pub fn lerp(self, other: Translation, t: Scalar) -> Translation {
+        Self::from_vector(self.0 * (1.0 - t) + other.0 * t)
+    }
+
+    pub const fn inverse(self) -> Translation {
+        Self::new(-self.0.x, -self.0.y)
+    }
 }
 
 impl From<Translation> for Vector {
@@ -108,4 +116,14 @@ mod tests {
         translation *= Translation::from(vector!(-3.0, 4.0));
         assert_approx_eq!(translation, Translation::from(vector!(-1.0, 7.0)));
     }
+
+    #[test]
+    fn lerp() {
+        let from = Translation::new(2.0, 3.0);
+        let to = Translation::new(-2.0, 7.0);
+
+        assert_approx_eq!(from.lerp(to, 0.0), from);
+        assert_approx_eq!(from.lerp(to, 0.25), Translation::new(1.0, 4.0));
+        assert_approx_eq!(from.lerp(to, 1.0), to);
+    }
 }
@@ -0,0 +1,106 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::{
+    geometry::{Scalar, Vector},
+    utils::approx::{approx_eq, ApproxEq},
+};
+
+// Rate of change of a similarity: turning speed, growth rate of the logarithm of the scale
+// and linear velocity. `Similarity::exp` turns a twist held for unit time into a similarity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimilarityTwist {
+    pub angular: Scalar,
+    pub log_scale: Scalar,
+    pub linear: Vector,
+}
+
+impl SimilarityTwist {
+    pub const ZERO: Self = Self::new(0.0, 0.0, Vector::ZERO);
+
+    pub const fn new(angular: Scalar, log_scale: Scalar, linear: Vector) -> Self {
+        Self {
+            angular,
+            log_scale,
+            linear,
+        }
+    }
+}
+
+impl Default for SimilarityTwist {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl ApproxEq for SimilarityTwist {
+    type Epsilon = <Scalar as ApproxEq>::Epsilon;
+
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        approx_eq!(self.angular, other.angular, epsilon)
+            && approx_eq!(self.log_scale, other.log_scale, epsilon)
+            && approx_eq!(self.linear, other.linear, epsilon)
+    }
+}
+
+impl Mul<Scalar> for SimilarityTwist {
+    type Output = SimilarityTwist;
+
+    fn mul(self, rhs: Scalar) -> Self::Output {
+        Self::new(self.angular * rhs, self.log_scale * rhs, self.linear * rhs)
+    }
+}
+
+impl Neg for SimilarityTwist {
+    type Output = SimilarityTwist;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl Add<SimilarityTwist> for SimilarityTwist {
+    type Output = SimilarityTwist;
+
+    fn add(self, rhs: SimilarityTwist) -> Self::Output {
+        Self::new(
+            self.angular + rhs.angular,
+            self.log_scale + rhs.log_scale,
+            self.linear + rhs.linear,
+        )
+    }
+}
+
+impl AddAssign<SimilarityTwist> for SimilarityTwist {
+    fn add_assign(&mut self, rhs: SimilarityTwist) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<SimilarityTwist> for SimilarityTwist {
+    type Output = SimilarityTwist;
+
+    fn sub(self, rhs: SimilarityTwist) -> Self::Output {
+        self + -rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::vector, utils::approx::assert_approx_eq};
+
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = SimilarityTwist::new(1.0, -0.5, vector!(2.0, 0.0));
+        let b = SimilarityTwist::new(0.5, 0.5, vector!(-1.0, 3.0));
+
+        assert_approx_eq!(a + b, SimilarityTwist::new(1.5, 0.0, vector!(1.0, 3.0)));
+        assert_approx_eq!(a - a, SimilarityTwist::ZERO);
+        assert_approx_eq!(a * 2.0, SimilarityTwist::new(2.0, -1.0, vector!(4.0, 0.0)));
+
+        let mut c = a;
+        c += b;
+        assert_approx_eq!(c, a + b);
+    }
+}
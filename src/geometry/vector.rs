@@ -51,6 +51,10 @@ impl Vector {
         self.norm_sq().sqrt()
     }
 
+    pub fn normalize(self) -> Vector {
+        self / self.norm()
+    }
+
     pub const fn complex_product(self, other: Vector) -> Vector {
         vector!(
             self.x * other.x - self.y * other.y,
@@ -232,6 +236,12 @@ mod tests {
         assert_approx_eq!(vector!(3.0, 4.0).norm(), 5.0);
     }
 
+    #[test]
+    fn normalize() {
+        assert_approx_eq!(vector!(3.0, 4.0).normalize(), vector!(0.6, 0.8));
+        assert_approx_eq!(vector!(0.0, -2.0).normalize(), -Vector::Y);
+    }
+
     #[test]
     fn complex_product() {
         assert_approx_eq!(
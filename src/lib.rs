@@ -2,5 +2,5 @@
 #![cfg(test)]
 extern crate test;
 
-mod geometry;
+pub mod geometry;
 mod utils;
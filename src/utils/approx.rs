@@ -124,8 +124,6 @@ pub(crate) use assert_approx_ne;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn f32_zero_epsilon() {
         assert!(approx_eq!(1.0_f32, 1.0_f32, 0.0));